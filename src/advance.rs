@@ -0,0 +1,80 @@
+use pattern::{ExactPattern, ReverseSearcher};
+use TrimMatchesExactlyExt;
+
+/// The extension trait adding methods for controlled trimming of a mutable `&str` or `&[u8]`,
+/// which advance the slice in place. This is convenient for streaming parsers.
+///
+/// `H` is the borrowed haystack, which patterns are trimmed off.
+pub trait TrimMatchesExactlyMutExt<'a, H: ?Sized + TrimMatchesExactlyExt + 'a> {
+    /// Advances the slice past pattern matches at its beginning given number of times.
+    /// If pattern can't be trimmed off that many times, leaves the slice untouched.
+    /// Returns `true` if the slice was advanced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyMutExt;
+    /// let mut input = "### Title";
+    /// assert!(!input.eat_left_exactly('#', 4));
+    /// assert_eq!("### Title", input);
+    /// assert!(input.eat_left_exactly('#', 3));
+    /// assert!(input.eat_left_exactly(' ', 1));
+    /// assert_eq!("Title", input);
+    /// ```
+    fn eat_left_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool;
+
+    /// Shrinks the slice by pattern matches at its end given number of times.
+    /// If pattern can't be trimmed off that many times, leaves the slice untouched.
+    /// Returns `true` if the slice was shrunk.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyMutExt;
+    /// let mut input = &b"data\r\n"[..];
+    /// assert!(input.eat_right_exactly(&b"\r\n"[..], 1));
+    /// assert_eq!(b"data", input);
+    /// ```
+    fn eat_right_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool
+        where P::Searcher: ReverseSearcher<'a, H>;
+}
+
+impl<'a, H: ?Sized + TrimMatchesExactlyExt> TrimMatchesExactlyMutExt<'a, H> for &'a H {
+    fn eat_left_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool {
+        match self.trim_left_matches_exactly(pat, count) {
+            Ok(trimmed) => {
+                *self = trimmed;
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    fn eat_right_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool
+            where P::Searcher: ReverseSearcher<'a, H> {
+        match self.trim_right_matches_exactly(pat, count) {
+            Ok(trimmed) => {
+                *self = trimmed;
+                true
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advances_str_on_success_only() {
+        let mut input = "aabaa";
+
+        assert!(input.eat_left_exactly('a', 2));
+        assert_eq!("baa", input);
+        assert!(!input.eat_right_exactly("a", 3));
+        assert_eq!("baa", input);
+        assert!(input.eat_right_exactly(|c| c == 'a', 2));
+        assert_eq!("b", input);
+        assert!(!input.eat_left_exactly("", 2));
+        assert_eq!("b", input);
+    }
+}
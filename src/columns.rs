@@ -0,0 +1,137 @@
+use unicode_width::UnicodeWidthChar;
+
+/// The extension trait adding methods for trimming given number of terminal columns off `str`.
+/// Wide chars, e.g. CJK ideographs, take 2 columns, zero-width chars take none.
+/// Zero-width chars, e.g. combining marks, are trimmed together with the char preceding them.
+/// Control chars, e.g. newlines and tabs, have no width, so trimming fails if it would reach them.
+///
+/// It's available with the `unicode-width` feature.
+pub trait TrimColumnsExactlyExt {
+    /// Returns '&str' with chars taking given number of columns trimmed from its beginning.
+    /// If it's narrower or the last trimmed char would be only partially trimmed,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimColumnsExactlyExt;
+    /// assert_eq!(Ok("text"), "    text".trim_left_columns_exactly(4));
+    /// assert_eq!(Ok("text"), "漢字text".trim_left_columns_exactly(4));
+    /// assert_eq!(Err("漢字text"), "漢字text".trim_left_columns_exactly(3));
+    /// assert_eq!(Ok("text"), "e\u{301}text".trim_left_columns_exactly(1));
+    /// assert_eq!(Err("\ttext"), "\ttext".trim_left_columns_exactly(1));
+    /// ```
+    fn trim_left_columns_exactly(&self, columns: usize) -> Result<&str, &str>;
+
+    /// Returns '&str' with chars taking given number of columns trimmed from its end.
+    /// If it's narrower or the last trimmed char would be only partially trimmed,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimColumnsExactlyExt;
+    /// assert_eq!(Ok("text"), "text漢字".trim_right_columns_exactly(4));
+    /// assert_eq!(Err("text漢字"), "text漢字".trim_right_columns_exactly(1));
+    /// assert_eq!(Ok("text"), "texte\u{301}".trim_right_columns_exactly(1));
+    /// ```
+    fn trim_right_columns_exactly(&self, columns: usize) -> Result<&str, &str>;
+}
+
+impl TrimColumnsExactlyExt for str {
+    fn trim_left_columns_exactly(&self, columns: usize) -> Result<&str, &str> {
+        if columns == 0 {
+            return Ok(self);
+        }
+        let mut chars = self.char_indices().peekable();
+        let mut trimmed_columns = 0;
+        while trimmed_columns < columns {
+            match chars.next() {
+                Some((_, c)) => match c.width() {
+                    Some(width) => trimmed_columns += width,
+                    None => return Err(self),
+                },
+                None => return Err(self),
+            }
+        }
+        if trimmed_columns > columns {
+            return Err(self);
+        }
+        while chars.next_if(|&(_, c)| c.width() == Some(0)).is_some() {}
+        let trim_idx = chars.peek().map_or(self.len(), |&(idx, _)| idx);
+        Ok(&self[trim_idx..])
+    }
+
+    fn trim_right_columns_exactly(&self, columns: usize) -> Result<&str, &str> {
+        let mut chars = self.char_indices();
+        let mut trimmed_columns = 0;
+        let mut trim_idx = self.len();
+        while trimmed_columns < columns {
+            match chars.next_back() {
+                Some((idx, c)) => match c.width() {
+                    Some(width) => {
+                        trimmed_columns += width;
+                        trim_idx = idx;
+                    },
+                    None => return Err(self),
+                },
+                None => return Err(self),
+            }
+        }
+        if trimmed_columns > columns {
+            return Err(self);
+        }
+        Ok(&self[..trim_idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod trim_left_columns_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, columns: usize) {
+            let actual = haystack.trim_left_columns_exactly(columns);
+
+            assert_eq!(expected, actual, "For haystack '{}' and columns '{}'", haystack, columns);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("\u{301}a漢b"), "\u{301}a漢b", 0);
+            assert_trim(Ok("漢b"),          "\u{301}a漢b", 1);
+            assert_trim(Err("\u{301}a漢b"), "\u{301}a漢b", 2);
+            assert_trim(Ok("b"),            "\u{301}a漢b", 3);
+            assert_trim(Ok(""),             "\u{301}a漢b", 4);
+            assert_trim(Err("\u{301}a漢b"), "\u{301}a漢b", 5);
+            assert_trim(Ok("b"),            "a\u{301}\u{200B}b", 1);
+            assert_trim(Ok("\nb"),          "a\nb", 1);
+            assert_trim(Err("\ta"),         "\ta", 1);
+            assert_trim(Err("a\tb"),        "a\tb", 2);
+        }
+    }
+
+    mod trim_right_columns_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, columns: usize) {
+            let actual = haystack.trim_right_columns_exactly(columns);
+
+            assert_eq!(expected, actual, "For haystack '{}' and columns '{}'", haystack, columns);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("b漢a\u{301}"), "b漢a\u{301}", 0);
+            assert_trim(Ok("b漢"),          "b漢a\u{301}", 1);
+            assert_trim(Err("b漢a\u{301}"), "b漢a\u{301}", 2);
+            assert_trim(Ok("b"),            "b漢a\u{301}", 3);
+            assert_trim(Ok(""),             "b漢a\u{301}", 4);
+            assert_trim(Err("b漢a\u{301}"), "b漢a\u{301}", 5);
+            assert_trim(Ok("\u{301}"),      "\u{301}a", 1);
+            assert_trim(Ok("a\n"),          "a\nb", 1);
+            assert_trim(Err("a\t"),         "a\t", 1);
+            assert_trim(Err("a\tb"),        "a\tb", 2);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use pattern::{ExactPattern, ReverseSearcher};
+use TrimMatchesExactlyExt;
+
+/// A position in the input of a [`Cursor`](struct.Cursor.html), which it can be rewound to
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Checkpoint {
+    start: usize,
+    end: usize,
+}
+
+/// The cursor for primitive parsing, which trims the input from both sides and tracks how much it consumed.
+///
+/// Every method trims only if the pattern matches exact number of times,
+/// otherwise it leaves the cursor unmodified. Any earlier state can be restored with a checkpoint.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::Cursor;
+/// let mut cursor = Cursor::new("  key = value;");
+/// assert!(cursor.eat_left_exactly(' ', 2));
+/// assert!(cursor.eat_right_exactly(';', 1));
+/// let checkpoint = cursor.checkpoint();
+/// assert!(cursor.eat_left_exactly("key", 1));
+/// assert!(!cursor.eat_left_exactly(':', 1));
+/// assert_eq!(" = value", cursor.remaining());
+/// cursor.rewind(checkpoint);
+/// assert_eq!("key = value", cursor.remaining());
+/// assert_eq!(2, cursor.left_offset());
+/// assert_eq!(13, cursor.right_offset());
+/// ```
+#[derive(Debug)]
+pub struct Cursor<'a, H: ?Sized + 'a = str> {
+    input: &'a H,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, H: ?Sized + TrimMatchesExactlyExt> Cursor<'a, H> {
+    /// Creates a cursor at the beginning and the end of the input
+    pub fn new(input: &'a H) -> Self {
+        Cursor { input, start: 0, end: input.len() }
+    }
+
+    /// Returns the whole input, including the consumed parts
+    pub fn input(&self) -> &'a H {
+        self.input
+    }
+
+    /// Returns the part of the input, which isn't consumed yet
+    pub fn remaining(&self) -> &'a H {
+        unsafe { self.input.slice_unchecked(self.start, self.end) }
+    }
+
+    /// Returns the byte offset in the input, at which the remaining part starts
+    pub fn left_offset(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset in the input, at which the remaining part ends
+    pub fn right_offset(&self) -> usize {
+        self.end
+    }
+
+    /// Consumes pattern matches from the beginning of the remaining part given number of times.
+    /// If pattern can't be trimmed off that many times, returns `false` and leaves the cursor unmodified.
+    pub fn eat_left_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool {
+        let remaining = self.remaining();
+        match remaining.trim_left_matches_exactly(pat, count) {
+            Ok(trimmed) => {
+                self.start += remaining.len() - trimmed.len();
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Consumes pattern matches from the end of the remaining part given number of times.
+    /// If pattern can't be trimmed off that many times, returns `false` and leaves the cursor unmodified.
+    pub fn eat_right_exactly<P: ExactPattern<'a, H>>(&mut self, pat: P, count: usize) -> bool
+            where P::Searcher: ReverseSearcher<'a, H> {
+        let remaining = self.remaining();
+        match remaining.trim_right_matches_exactly(pat, count) {
+            Ok(trimmed) => {
+                self.end -= remaining.len() - trimmed.len();
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the current position of the cursor
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint { start: self.start, end: self.end }
+    }
+
+    /// Restores the position of the cursor.
+    ///
+    /// # Panics
+    /// Panics if the checkpoint doesn't lie on boundaries of the input,
+    /// which may happen if it was created by a cursor over a different input.
+    pub fn rewind(&mut self, checkpoint: Checkpoint) {
+        assert!(checkpoint.start <= checkpoint.end && checkpoint.end <= self.input.len()
+            && self.input.is_boundary(checkpoint.start) && self.input.is_boundary(checkpoint.end),
+            "checkpoint doesn't lie on boundaries of the input");
+        self.start = checkpoint.start;
+        self.end = checkpoint.end;
+    }
+}
+
+impl<'a, H: ?Sized> Clone for Cursor<'a, H> {
+    fn clone(&self) -> Self {
+        Cursor { input: self.input, start: self.start, end: self.end }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eats_from_both_sides_and_rewinds() {
+        let mut cursor = Cursor::new("aabaa");
+        let initial = cursor.checkpoint();
+
+        assert!(cursor.eat_left_exactly('a', 2));
+        assert!(!cursor.eat_right_exactly('a', 3));
+        assert!(cursor.eat_right_exactly('a', 2));
+        assert_eq!("b", cursor.remaining());
+        assert_eq!((2, 3), (cursor.left_offset(), cursor.right_offset()));
+        assert!(cursor.eat_left_exactly('b', 1));
+        assert!(!cursor.eat_right_exactly('b', 1));
+        assert_eq!("", cursor.remaining());
+
+        cursor.rewind(initial);
+        assert_eq!("aabaa", cursor.remaining());
+        assert_eq!((0, 5), (cursor.left_offset(), cursor.right_offset()));
+    }
+
+    #[test]
+    fn eats_bytes() {
+        let mut cursor = Cursor::new(&b"aab"[..]);
+
+        assert!(cursor.eat_left_exactly(b'a', 2));
+        assert_eq!(&b"b"[..], cursor.remaining());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_checkpoint_not_on_char_boundary() {
+        let checkpoint = {
+            let mut cursor = Cursor::new("ab");
+            cursor.eat_left_exactly('a', 1);
+            cursor.checkpoint()
+        };
+        Cursor::new("ł").rewind(checkpoint);
+    }
+}
@@ -0,0 +1,88 @@
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// The reason why trimming failed
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TrimErrorKind {
+    /// The whole haystack was trimmed off before the pattern matched enough times
+    InputEnded,
+    /// The pattern didn't match the haystack
+    Mismatch,
+}
+
+/// The error returned when a pattern can't be trimmed off
+pub struct TrimError<'a, H: ?Sized + 'a = str> {
+    haystack: &'a H,
+    matches: usize,
+    position: usize,
+    kind: TrimErrorKind,
+}
+
+impl<'a, H: ?Sized> TrimError<'a, H> {
+    pub(crate) fn new(haystack: &'a H, matches: usize, position: usize, kind: TrimErrorKind) -> Self {
+        TrimError { haystack, matches, position, kind }
+    }
+
+    /// Returns the untrimmed haystack
+    pub fn haystack(&self) -> &'a H {
+        self.haystack
+    }
+
+    /// Returns the number of times the pattern matched before trimming failed
+    pub fn matches(&self) -> usize {
+        self.matches
+    }
+
+    /// Returns the byte offset in the haystack, at which the pattern stopped matching
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason why trimming failed
+    pub fn kind(&self) -> TrimErrorKind {
+        self.kind
+    }
+}
+
+impl<'a, H: ?Sized> Clone for TrimError<'a, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, H: ?Sized> Copy for TrimError<'a, H> {}
+
+impl<'a, H: ?Sized + PartialEq> PartialEq for TrimError<'a, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.haystack == other.haystack && self.matches == other.matches
+            && self.position == other.position && self.kind == other.kind
+    }
+}
+
+impl<'a, H: ?Sized + Eq> Eq for TrimError<'a, H> {}
+
+impl<'a, H: ?Sized + Debug> Debug for TrimError<'a, H> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrimError")
+            .field("haystack", &self.haystack)
+            .field("matches", &self.matches)
+            .field("position", &self.position)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<'a, H: ?Sized> Display for TrimError<'a, H> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            TrimErrorKind::InputEnded =>
+                write!(f, "pattern matched {} times before the input ended at byte {}",
+                    self.matches, self.position),
+            TrimErrorKind::Mismatch =>
+                write!(f, "pattern matched {} times before a mismatch at byte {}",
+                    self.matches, self.position),
+        }
+    }
+}
+
+impl<'a, H: ?Sized + Debug> Error for TrimError<'a, H> {}
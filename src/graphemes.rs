@@ -0,0 +1,39 @@
+use pattern::AnyGrapheme;
+use TrimMatchesExactlyExt;
+
+/// The extension trait adding methods for trimming whole extended grapheme clusters off `str`.
+/// The trimmed `&str` always starts and ends at a grapheme cluster boundary.
+///
+/// It's available with the `unicode-segmentation` feature.
+pub trait TrimGraphemesExactlyExt {
+    /// Returns '&str' with given number of grapheme clusters trimmed from its beginning.
+    /// If it has fewer grapheme clusters, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimGraphemesExactlyExt;
+    /// assert_eq!(Ok("b"), "e\u{301}\u{1F469}\u{200D}\u{1F52C}b".trim_left_graphemes_exactly(2));
+    /// assert_eq!(Err("e\u{301}"), "e\u{301}".trim_left_graphemes_exactly(2));
+    /// ```
+    fn trim_left_graphemes_exactly(&self, count: usize) -> Result<&str, &str>;
+
+    /// Returns '&str' with given number of grapheme clusters trimmed from its end.
+    /// If it has fewer grapheme clusters, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimGraphemesExactlyExt;
+    /// assert_eq!(Ok("caf"), "cafe\u{301}".trim_right_graphemes_exactly(1));
+    /// ```
+    fn trim_right_graphemes_exactly(&self, count: usize) -> Result<&str, &str>;
+}
+
+impl TrimGraphemesExactlyExt for str {
+    fn trim_left_graphemes_exactly(&self, count: usize) -> Result<&str, &str> {
+        self.trim_left_matches_exactly(AnyGrapheme, count)
+    }
+
+    fn trim_right_graphemes_exactly(&self, count: usize) -> Result<&str, &str> {
+        self.trim_right_matches_exactly(AnyGrapheme, count)
+    }
+}
@@ -0,0 +1,168 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(all(feature = "std", unix))]
+use core::mem;
+#[cfg(all(feature = "std", unix))]
+use std::ffi::{OsStr, OsString};
+#[cfg(all(feature = "std", unix))]
+use std::os::unix::ffi::OsStringExt;
+
+use pattern::{ExactPattern, ReverseSearcher};
+use TrimMatchesExactlyExt;
+
+/// The extension trait adding methods for controlled in-place trimming of `String` and `Vec<u8>`.
+/// With the `std` feature on Unix it's implemented for `OsString`.
+///
+/// `H` is the borrowed haystack, which patterns are trimmed off.
+pub trait TrimMatchesExactlyInPlaceExt<H: ?Sized + TrimMatchesExactlyExt> {
+    /// Removes pattern matches from the beginning of the string given number of times.
+    /// If pattern can't be trimmed off that many times, leaves the string untouched.
+    /// Returns `true` if the string was trimmed. The capacity of the string is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyInPlaceExt;
+    /// let mut string = String::from("tttrimmed");
+    /// assert!(string.trim_left_matches_exactly_in_place('t', 2));
+    /// assert_eq!("trimmed", string);
+    /// assert!(!string.trim_left_matches_exactly_in_place("very ", 1));
+    /// assert_eq!("trimmed", string);
+    ///
+    /// let mut bytes = b"\0\0data".to_vec();
+    /// assert!(bytes.trim_left_matches_exactly_in_place(0, 2));
+    /// assert_eq!(b"data", &bytes[..]);
+    /// ```
+    fn trim_left_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+        where P: for<'a> ExactPattern<'a, H>;
+
+    /// Removes pattern matches from the end of the string given number of times.
+    /// If pattern can't be trimmed off that many times, leaves the string untouched.
+    /// Returns `true` if the string was trimmed. The capacity of the string is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyInPlaceExt;
+    /// let mut string = String::from("trimmm");
+    /// assert!(string.trim_right_matches_exactly_in_place('m', 2));
+    /// assert_eq!("trim", string);
+    /// assert!(!string.trim_right_matches_exactly_in_place(" you!", 1));
+    /// assert_eq!("trim", string);
+    /// ```
+    fn trim_right_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+        where P: for<'a> ExactPattern<'a, H>,
+            for<'a> <P as ExactPattern<'a, H>>::Searcher: ReverseSearcher<'a, H>;
+}
+
+macro_rules! trim_matches_exactly_in_place_impl {
+    ($owned:ty, $haystack:ty) => {
+        impl TrimMatchesExactlyInPlaceExt<$haystack> for $owned {
+            fn trim_left_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+                    where P: for<'a> ExactPattern<'a, $haystack> {
+                let trim_idx = match self.trim_left_matches_exactly(pat, count) {
+                    Ok(trimmed) => self.len() - trimmed.len(),
+                    Err(_) => return false,
+                };
+                self.drain(..trim_idx);
+                true
+            }
+
+            fn trim_right_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+                    where P: for<'a> ExactPattern<'a, $haystack>,
+                        for<'a> <P as ExactPattern<'a, $haystack>>::Searcher: ReverseSearcher<'a, $haystack> {
+                let trim_idx = match self.trim_right_matches_exactly(pat, count) {
+                    Ok(trimmed) => trimmed.len(),
+                    Err(_) => return false,
+                };
+                self.truncate(trim_idx);
+                true
+            }
+        }
+    };
+}
+
+trim_matches_exactly_in_place_impl!(String, str);
+trim_matches_exactly_in_place_impl!(Vec<u8>, [u8]);
+
+#[cfg(all(feature = "std", unix))]
+impl TrimMatchesExactlyInPlaceExt<OsStr> for OsString {
+    fn trim_left_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+            where P: for<'a> ExactPattern<'a, OsStr> {
+        let trim_idx = match self.trim_left_matches_exactly(pat, count) {
+            Ok(trimmed) => self.len() - trimmed.len(),
+            Err(_) => return false,
+        };
+        let mut bytes = mem::take(self).into_vec();
+        bytes.drain(..trim_idx);
+        *self = OsString::from_vec(bytes);
+        true
+    }
+
+    fn trim_right_matches_exactly_in_place<P>(&mut self, pat: P, count: usize) -> bool
+            where P: for<'a> ExactPattern<'a, OsStr>,
+                for<'a> <P as ExactPattern<'a, OsStr>>::Searcher: ReverseSearcher<'a, OsStr> {
+        let trim_idx = match self.trim_right_matches_exactly(pat, count) {
+            Ok(trimmed) => trimmed.len(),
+            Err(_) => return false,
+        };
+        let mut bytes = mem::take(self).into_vec();
+        bytes.truncate(trim_idx);
+        *self = OsString::from_vec(bytes);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::borrow::ToOwned;
+
+    #[test]
+    fn trims_in_place_keeping_capacity() {
+        let mut string = String::with_capacity(16);
+        string.push_str("aabaa");
+
+        assert!(string.trim_left_matches_exactly_in_place('a', 1));
+        assert_eq!("abaa", string);
+        assert!(string.trim_right_matches_exactly_in_place("a", 2));
+        assert_eq!("ab", string);
+        assert!(!string.trim_left_matches_exactly_in_place('a', 2));
+        assert_eq!("ab", string);
+        assert!(!string.trim_right_matches_exactly_in_place("a", 1));
+        assert_eq!("ab", string);
+        assert_eq!(16, string.capacity());
+    }
+
+    #[test]
+    fn accepts_borrowed_patterns() {
+        let mut string = "not trimmed".to_owned();
+        let pattern = "not ".to_owned();
+
+        assert!(string.trim_left_matches_exactly_in_place(&pattern, 1));
+        assert_eq!("trimmed", string);
+    }
+
+    #[test]
+    fn trims_bytes_in_place() {
+        let mut bytes = b"\r\n\r\nbody\r\n".to_vec();
+
+        assert!(bytes.trim_left_matches_exactly_in_place(b"\r\n", 2));
+        assert_eq!(b"body\r\n", &bytes[..]);
+        assert!(bytes.trim_right_matches_exactly_in_place(*b"\r\n", 2));
+        assert_eq!(b"body", &bytes[..]);
+        assert!(!bytes.trim_right_matches_exactly_in_place(b'\n', 1));
+        assert_eq!(b"body", &bytes[..]);
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn trims_os_string_in_place() {
+        let mut os_string = OsString::from("--verbose=yes");
+
+        assert!(os_string.trim_left_matches_exactly_in_place('-', 2));
+        assert_eq!("verbose=yes", os_string);
+        assert!(os_string.trim_right_matches_exactly_in_place(OsStr::new("=yes"), 1));
+        assert_eq!("verbose", os_string);
+        assert!(!os_string.trim_left_matches_exactly_in_place("-", 1));
+        assert_eq!("verbose", os_string);
+    }
+}
@@ -0,0 +1,170 @@
+/// The extension trait adding methods for trimming indentation made of spaces and tabs off `str`.
+/// A space takes 1 column, a tab moves to the next tab stop.
+pub trait TrimIndentExactlyExt {
+    /// Returns '&str' with leading spaces and tabs taking given number of columns trimmed.
+    /// If the indentation is narrower, a tab crosses the column or tabs left in the indentation
+    /// would move to other tab stops, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Panics
+    /// Panics if `tab_width` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimIndentExactlyExt;
+    /// assert_eq!(Ok("code"), "\t    code".trim_indent_exactly(8, 4));
+    /// assert_eq!(Ok("  code"), "  \t  code".trim_indent_exactly(4, 4));
+    /// assert_eq!(Err("  \tcode"), "  \tcode".trim_indent_exactly(3, 4));
+    /// assert_eq!(Err("  code"), "  code".trim_indent_exactly(4, 4));
+    /// assert_eq!(Err("  \tcode"), "  \tcode".trim_indent_exactly(2, 4));
+    /// ```
+    fn trim_indent_exactly(&self, columns: usize, tab_width: usize) -> Result<&str, &str>;
+
+    /// Returns '&str' with leading spaces and tabs taking given number of columns trimmed.
+    /// If a tab crosses the column, it's trimmed too. Also returns the number of spaces,
+    /// which must be prepended to the trimmed `&str` to keep its indentation.
+    /// If tabs left in the indentation would move to other tab stops,
+    /// the whole indentation is trimmed and counted in the spaces.
+    /// If the indentation is narrower, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Panics
+    /// Panics if `tab_width` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimIndentExactlyExt;
+    /// assert_eq!(Ok(("code", 1)), "  \tcode".trim_indent_splitting_tabs(3, 4));
+    /// assert_eq!(Ok((" code", 0)), "\t code".trim_indent_splitting_tabs(4, 4));
+    /// assert_eq!(Ok(("code", 6)), "  \t\tcode".trim_indent_splitting_tabs(2, 4));
+    /// assert_eq!(Err("  code"), "  code".trim_indent_splitting_tabs(3, 4));
+    /// ```
+    fn trim_indent_splitting_tabs(&self, columns: usize, tab_width: usize) -> Result<(&str, usize), &str>;
+}
+
+/// Walks the indentation until it reaches the columns,
+/// returns the byte offset and the column, at which it stopped
+fn indent_idx(haystack: &str, columns: usize, tab_width: usize) -> Option<(usize, usize)> {
+    assert!(tab_width != 0, "Tab width must be positive");
+    let mut column = 0;
+    for (idx, byte) in haystack.bytes().enumerate() {
+        if column >= columns {
+            return Some((idx, column));
+        }
+        match byte {
+            b' ' => column += 1,
+            b'\t' => column = (column / tab_width + 1) * tab_width,
+            _ => return None,
+        }
+    }
+    if column >= columns { Some((haystack.len(), column)) } else { None }
+}
+
+/// Checks if the rest of the indentation contains a tab, which would move to another tab stop
+/// after trimming given number of columns
+fn moves_tabs(rest: &str, columns: usize, tab_width: usize) -> bool {
+    !columns.is_multiple_of(tab_width) && rest.bytes()
+        .take_while(|&byte| byte == b' ' || byte == b'\t')
+        .any(|byte| byte == b'\t')
+}
+
+/// Walks the whole indentation starting at the column,
+/// returns the byte offset and the column, at which it ends
+fn indent_end(haystack: &str, mut column: usize, tab_width: usize) -> (usize, usize) {
+    for (idx, byte) in haystack.bytes().enumerate() {
+        match byte {
+            b' ' => column += 1,
+            b'\t' => column = (column / tab_width + 1) * tab_width,
+            _ => return (idx, column),
+        }
+    }
+    (haystack.len(), column)
+}
+
+impl TrimIndentExactlyExt for str {
+    fn trim_indent_exactly(&self, columns: usize, tab_width: usize) -> Result<&str, &str> {
+        match indent_idx(self, columns, tab_width) {
+            Some((trim_idx, column)) if column == columns => {
+                let rest = &self[trim_idx..];
+                if moves_tabs(rest, columns, tab_width) { Err(self) } else { Ok(rest) }
+            },
+            _ => Err(self),
+        }
+    }
+
+    fn trim_indent_splitting_tabs(&self, columns: usize, tab_width: usize) -> Result<(&str, usize), &str> {
+        match indent_idx(self, columns, tab_width) {
+            Some((trim_idx, column)) => {
+                let rest = &self[trim_idx..];
+                if moves_tabs(rest, columns, tab_width) {
+                    let (end_idx, end_column) = indent_end(rest, column, tab_width);
+                    Ok((&rest[end_idx..], end_column - columns))
+                } else {
+                    Ok((rest, column - columns))
+                }
+            },
+            None => Err(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod trim_indent_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, columns: usize, tab_width: usize) {
+            let actual = haystack.trim_indent_exactly(columns, tab_width);
+
+            assert_eq!(expected, actual,
+                "For haystack '{:?}', columns '{}' and tab width '{}'", haystack, columns, tab_width);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok(" \t a"),  " \t a", 0, 4);
+            assert_trim(Err(" \t a"), " \t a", 1, 4);
+            assert_trim(Err(" \t a"), " \t a", 2, 4);
+            assert_trim(Ok(" a"),     " \t a", 4, 4);
+            assert_trim(Ok("a"),      " \t a", 5, 4);
+            assert_trim(Err(" \t a"), " \t a", 6, 4);
+            assert_trim(Ok(" a"),     " \t a", 2, 2);
+            assert_trim(Ok(""),       "\t",    8, 8);
+            assert_trim(Err("  \t\ta"), "  \t\ta", 2, 4);
+            assert_trim(Ok("\ta"),    "\t\ta",  4, 4);
+            assert_trim(Ok("\ta"),    "  \ta",  2, 2);
+            assert_trim(Err(" \t\ta"), " \t\ta", 1, 2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn rejects_zero_tab_width() {
+            let _ = "\ta".trim_indent_exactly(1, 0);
+        }
+    }
+
+    mod trim_indent_splitting_tabs {
+        use super::*;
+
+        fn assert_trim(expected: Result<(&str, usize), &str>, haystack: &str, columns: usize,
+                tab_width: usize) {
+            let actual = haystack.trim_indent_splitting_tabs(columns, tab_width);
+
+            assert_eq!(expected, actual,
+                "For haystack '{:?}', columns '{}' and tab width '{}'", haystack, columns, tab_width);
+        }
+
+        #[test]
+        fn returns_trimmed_str_and_spaces_or_original_str() {
+            assert_trim(Ok((" \t a", 0)), " \t a", 0, 4);
+            assert_trim(Ok((" a", 2)),    " \t a", 2, 4);
+            assert_trim(Ok((" a", 0)),    " \t a", 4, 4);
+            assert_trim(Ok(("a", 0)),     " \t a", 5, 4);
+            assert_trim(Err(" \t a"),     " \t a", 6, 4);
+            assert_trim(Ok(("a", 4)),     " \t a", 1, 4);
+            assert_trim(Ok(("a", 6)),     "  \t\ta", 2, 4);
+            assert_trim(Ok(("a", 5)),     "  \t\ta", 3, 4);
+            assert_trim(Ok(("\ta", 0)),   "\t\ta", 4, 4);
+        }
+    }
+}
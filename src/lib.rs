@@ -1,15 +1,78 @@
 //! Extension trait for controlled trimming of prefixes and suffixes of `&str` and `String`.
+//! The same methods are provided for byte slices `&[u8]` and `Vec<u8>`.
 //!
 //! Provided methods trim only if the given pattern matches exact number of times, otherwise
 //! they return the unmodified `&str`. This can be used for primitive parsing and text analysis.
+//! The [`Cursor`](struct.Cursor.html) tracks the parsing position and allows backtracking.
+//!
+//! The patterns are described by the [`ExactPattern`](pattern/trait.ExactPattern.html) trait.
+//! It's implemented for `char`, `&str`, `&String`, `&[char]`, `[char; N]` and `FnMut(char) -> bool`
+//! closures. With the `nightly` feature it's implemented for every `core::str::pattern::Pattern`.
+//! Byte slices can be trimmed with bytes, byte strings, byte sets and `FnMut(u8) -> bool` closures.
+//!
+//! The `std` feature adds trimming of `OsStr` and `OsString` on Unix
+//! and stripping exact number of `Path` components.
+//! The `unicode-segmentation` feature adds trimming of whole grapheme clusters
+//! and the `unicode-width` feature adds trimming of terminal columns.
 
-#![feature(pattern)]
+#![cfg_attr(feature = "nightly", feature(pattern))]
 #![no_std]
 
-use core::str::pattern::{Pattern, ReverseSearcher, Searcher, SearchStep};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "aho-corasick")]
+extern crate aho_corasick;
+#[cfg(feature = "regex-automata")]
+extern crate regex_automata;
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+#[cfg(feature = "unicode-width")]
+extern crate unicode_width;
+
+pub mod pattern;
+mod advance;
+#[cfg(feature = "unicode-width")]
+mod columns;
+mod cursor;
+mod error;
+#[cfg(feature = "unicode-segmentation")]
+mod graphemes;
+#[cfg(feature = "alloc")]
+mod in_place;
+mod indent;
+#[cfg(feature = "alloc")]
+mod lines;
+#[cfg(feature = "std")]
+mod path;
+mod quantifier;
+mod sequence;
+mod units;
 
-/// The extension trait adding methods for controlled trimming
-pub trait TrimMatchesExactlyExt {
+use pattern::{ExactPattern, Haystack, ReverseSearcher, Searcher, SearchStep};
+pub use advance::TrimMatchesExactlyMutExt;
+#[cfg(feature = "unicode-width")]
+pub use columns::TrimColumnsExactlyExt;
+pub use cursor::{Checkpoint, Cursor};
+pub use error::{TrimError, TrimErrorKind};
+#[cfg(feature = "unicode-segmentation")]
+pub use graphemes::TrimGraphemesExactlyExt;
+#[cfg(feature = "alloc")]
+pub use in_place::TrimMatchesExactlyInPlaceExt;
+pub use indent::TrimIndentExactlyExt;
+#[cfg(feature = "alloc")]
+pub use lines::{BlankLines, LineError, TrimLinesExactlyExt, TrimmedLines};
+#[cfg(feature = "std")]
+pub use path::StripComponentsExactlyExt;
+pub use quantifier::Quantifier;
+pub use sequence::{ReverseSequence, Sequence, SequenceError};
+pub use units::{TrimUnitsExactlyExt, Unit};
+
+/// The extension trait adding methods for controlled trimming.
+///
+/// It's implemented for `str` and `[u8]`. With the `std` feature on Unix it's implemented for `OsStr`.
+pub trait TrimMatchesExactlyExt: Haystack {
     /// Returns '&str' with pattern matches trimmed from its beginning given number of times.
     /// If pattern can't be trimmed off that many times, returns `Err` with an untrimmed `&str`.
     /// This can be used for primitive parsing and text analysis.
@@ -27,9 +90,13 @@ pub trait TrimMatchesExactlyExt {
     /// assert_eq!(Ok("trimmed"), "not trimmed".trim_left_matches_exactly("not ", 1));
     /// assert_eq!(Err("not trimmed"), "not trimmed".trim_left_matches_exactly("very ", 1));
     /// assert_eq!(Ok("trimmed"), "tttrimmed".trim_left_matches_exactly('t', 2));
+    /// assert_eq!(Ok(&b"trimmed"[..]), b"tttrimmed"[..].trim_left_matches_exactly(b't', 2));
     /// ```
-    fn trim_left_matches_exactly<'a, P: Pattern<'a>>(&'a self, pat: P, count: usize)
-        -> Result<&'a str, &'a str>;
+    fn trim_left_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, &'a Self> {
+        self.try_trim_left_matches_exactly(pat, count).map_err(|error| error.haystack())
+    }
+
     /// Returns '&str' with pattern matches trimmed from its end given number of times.
     /// If pattern can't be trimmed off that many times, returns `Err` with an untrimmed `&str`.
     /// This can be used for primitive parsing and text analysis.
@@ -47,45 +114,508 @@ pub trait TrimMatchesExactlyExt {
     /// assert_eq!(Ok("trim"), "trim me!".trim_right_matches_exactly(" me!", 1));
     /// assert_eq!(Err("trim me!"), "trim me!".trim_right_matches_exactly(" you!", 1));
     /// assert_eq!(Ok("trim"), "trimmm".trim_right_matches_exactly('m', 2));
+    /// assert_eq!(Ok(&b"trim"[..]), b"trim\r\n"[..].trim_right_matches_exactly(b"\r\n", 1));
     /// ```
-    fn trim_right_matches_exactly<'a, P: Pattern<'a>>(&'a self, pat: P, count: usize)
-        -> Result<&'a str, &'a str>
-        where P::Searcher: ReverseSearcher<'a>;
-}
+    fn trim_right_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        self.try_trim_right_matches_exactly(pat, count).map_err(|error| error.haystack())
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its beginning given number of times.
+    /// If pattern can't be trimmed off that many times, returns a [`TrimError`](struct.TrimError.html)
+    /// describing how far trimming got.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{TrimErrorKind, TrimMatchesExactlyExt};
+    /// assert_eq!(Ok("trimmed"), "tttrimmed".try_trim_left_matches_exactly('t', 2));
+    ///
+    /// let error = "ttrimmed".try_trim_left_matches_exactly('t', 3).unwrap_err();
+    /// assert_eq!("ttrimmed", error.haystack());
+    /// assert_eq!(2, error.matches());
+    /// assert_eq!(2, error.position());
+    /// assert_eq!(TrimErrorKind::Mismatch, error.kind());
+    /// assert_eq!("pattern matched 2 times before a mismatch at byte 2", error.to_string());
+    /// ```
+    fn try_trim_left_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, TrimError<'a, Self>> {
+        let trim_idx = left_trim_idx(&mut pat.into_searcher(self), count)?;
+        unsafe { Ok(self.slice_unchecked(trim_idx, self.len())) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its end given number of times.
+    /// If pattern can't be trimmed off that many times, returns a [`TrimError`](struct.TrimError.html)
+    /// describing how far trimming got.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{TrimErrorKind, TrimMatchesExactlyExt};
+    /// assert_eq!(Ok("trim"), "trimmm".try_trim_right_matches_exactly('m', 2));
+    ///
+    /// let error = "mm".try_trim_right_matches_exactly('m', 3).unwrap_err();
+    /// assert_eq!(2, error.matches());
+    /// assert_eq!(0, error.position());
+    /// assert_eq!(TrimErrorKind::InputEnded, error.kind());
+    /// ```
+    fn try_trim_right_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, TrimError<'a, Self>>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let trim_idx = right_trim_idx(&mut pat.into_searcher(self), count)?;
+        unsafe { Ok(self.slice_unchecked(0, trim_idx)) }
+    }
 
-impl TrimMatchesExactlyExt for str {
-    fn trim_left_matches_exactly<'a, P: Pattern<'a>>(&'a self, pat: P, count: usize)
-            -> Result<&'a str, &'a str> {
+    /// Returns '&str' with pattern matches trimmed from its beginning as many times as possible,
+    /// but not more than the quantifier allows. Also returns the number of trimmed matches.
+    /// If pattern can't be trimmed off as many times as the quantifier requires,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{Quantifier, TrimMatchesExactlyExt};
+    /// assert_eq!(Ok(("Title", 3)), "###Title".trim_left_matches_quantified('#', 2..=4));
+    /// assert_eq!(Ok(("#Title", 4)), "#####Title".trim_left_matches_quantified('#', 2..=4));
+    /// assert_eq!(Err("#Title"), "#Title".trim_left_matches_quantified('#', 2..=4));
+    /// assert_eq!(Ok(("code", 5)), "`````code".trim_left_matches_quantified('`', 3..));
+    /// assert_eq!(Ok(("text", 0)), "text".trim_left_matches_quantified('\u{feff}', ..=1));
+    /// assert_eq!(Ok(("trimmed", 2)), "tttrimmed".trim_left_matches_quantified('t', 2));
+    /// ```
+    fn trim_left_matches_quantified<'a, P, Q>(&'a self, pat: P, quantifier: Q)
+            -> Result<(&'a Self, usize), &'a Self>
+            where P: ExactPattern<'a, Self>, Q: Into<Quantifier> {
+        let quantifier = quantifier.into();
+        let (count, trim_idx) = self.count_left_matches(pat, quantifier.max());
+        if count < quantifier.min() {
+            return Err(self);
+        }
+        unsafe { Ok((self.slice_unchecked(trim_idx, self.len()), count)) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its end as many times as possible,
+    /// but not more than the quantifier allows. Also returns the number of trimmed matches.
+    /// If pattern can't be trimmed off as many times as the quantifier requires,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{Quantifier, TrimMatchesExactlyExt};
+    /// assert_eq!(Ok(("line", 1)), "line\n".trim_right_matches_quantified('\n', ..=1));
+    /// assert_eq!(Ok(("line\n", 1)), "line\n\n".trim_right_matches_quantified('\n', ..=1));
+    /// assert_eq!(Err("trim"), "trim".trim_right_matches_quantified('m', Quantifier::AtLeast(2)));
+    /// ```
+    fn trim_right_matches_quantified<'a, P, Q>(&'a self, pat: P, quantifier: Q)
+            -> Result<(&'a Self, usize), &'a Self>
+            where P: ExactPattern<'a, Self>, P::Searcher: ReverseSearcher<'a, Self>, Q: Into<Quantifier> {
+        let quantifier = quantifier.into();
+        let (count, trim_idx) = self.count_right_matches(pat, quantifier.max());
+        if count < quantifier.min() {
+            return Err(self);
+        }
+        unsafe { Ok((self.slice_unchecked(0, trim_idx), count)) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its beginning given number of times.
+    /// If pattern can't be trimmed off that many times or if it matches once more after that,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok(" Title"), "## Title".trim_left_matches_strictly('#', 2));
+    /// assert_eq!(Err("### Title"), "### Title".trim_left_matches_strictly('#', 2));
+    /// assert_eq!(Err("# Title"), "# Title".trim_left_matches_strictly('#', 2));
+    /// ```
+    fn trim_left_matches_strictly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, &'a Self> {
         let mut matcher = pat.into_searcher(self);
-        unsafe {
-            let mut trim_idx = 0;
-            for _ in 0..count {
-                match matcher.next() {
-                    SearchStep::Match(_, match_end) => trim_idx = match_end,
-                    _ => return Err(self),
-                }
+        let trim_idx = left_trim_idx(&mut matcher, count).map_err(|error| error.haystack())?;
+        if let SearchStep::Match(..) = matcher.next() {
+            return Err(self);
+        }
+        unsafe { Ok(self.slice_unchecked(trim_idx, self.len())) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its end given number of times.
+    /// If pattern can't be trimmed off that many times or if it matches once more after that,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("> quote"), "> quote\n\n".trim_right_matches_strictly('\n', 2));
+    /// assert_eq!(Err("> quote\n\n\n"), "> quote\n\n\n".trim_right_matches_strictly('\n', 2));
+    /// ```
+    fn trim_right_matches_strictly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let mut matcher = pat.into_searcher(self);
+        let trim_idx = right_trim_idx(&mut matcher, count).map_err(|error| error.haystack())?;
+        if let SearchStep::Match(..) = matcher.next_back() {
+            return Err(self);
+        }
+        unsafe { Ok(self.slice_unchecked(0, trim_idx)) }
+    }
+
+    /// Splits '&str' into pattern matches trimmed from its beginning given number of times
+    /// and the rest. If pattern can't be trimmed off that many times,
+    /// returns `Err` with an unsplit `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok(("###", " Title")), "### Title".split_left_matches_exactly('#', 3));
+    /// assert_eq!(Err("## Title"), "## Title".split_left_matches_exactly('#', 3));
+    /// ```
+    fn split_left_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<(&'a Self, &'a Self), &'a Self> {
+        let trim_idx = left_trim_idx(&mut pat.into_searcher(self), count)
+            .map_err(|error| error.haystack())?;
+        unsafe { Ok((self.slice_unchecked(0, trim_idx), self.slice_unchecked(trim_idx, self.len()))) }
+    }
+
+    /// Splits '&str' into the rest and pattern matches trimmed from its end given number of times.
+    /// If pattern can't be trimmed off that many times, returns `Err` with an unsplit `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok(("Title ", "###")), "Title ###".split_right_matches_exactly('#', 3));
+    /// assert_eq!(Err("Title ##"), "Title ##".split_right_matches_exactly('#', 3));
+    /// ```
+    fn split_right_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<(&'a Self, &'a Self), &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let trim_idx = right_trim_idx(&mut pat.into_searcher(self), count)
+            .map_err(|error| error.haystack())?;
+        unsafe { Ok((self.slice_unchecked(0, trim_idx), self.slice_unchecked(trim_idx, self.len()))) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its beginning and its end given numbers
+    /// of times. If pattern can't be trimmed off that many times or if the matches trimmed from
+    /// both the sides would overlap, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("bold"), "**bold**".trim_matches_exactly('*', 2, 2));
+    /// assert_eq!(Ok("a"), "aaa".trim_matches_exactly('a', 1, 1));
+    /// assert_eq!(Err("aaa"), "aaa".trim_matches_exactly('a', 2, 2));
+    /// assert_eq!(Err("aaa"), "aaa".trim_matches_exactly("aa", 1, 1));
+    /// ```
+    fn trim_matches_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, left: usize, right: usize)
+            -> Result<&'a Self, &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let mut matcher = pat.into_searcher(self);
+        let left_idx = left_trim_idx(&mut matcher, left).map_err(|error| error.haystack())?;
+        let right_idx = right_trim_idx(&mut matcher, right).map_err(|error| error.haystack())?;
+        if left_idx > right_idx {
+            return Err(self);
+        }
+        unsafe { Ok(self.slice_unchecked(left_idx, right_idx)) }
+    }
+
+    /// Returns '&str' with left pattern matches trimmed from its beginning and right pattern
+    /// matches trimmed from its end given numbers of times. If patterns can't be trimmed off that
+    /// many times or if the matches trimmed from both the sides would overlap,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("quote"), "<<quote>>".trim_both_matches_exactly('<', 2, '>', 2));
+    /// assert_eq!(Ok(""), "<>".trim_both_matches_exactly('<', 1, '>', 1));
+    /// assert_eq!(Err("a"), "a".trim_both_matches_exactly('a', 1, 'a', 1));
+    /// ```
+    fn trim_both_matches_exactly<'a, L, R>(&'a self, left_pat: L, left: usize, right_pat: R, right: usize)
+            -> Result<&'a Self, &'a Self>
+            where L: ExactPattern<'a, Self>, R: ExactPattern<'a, Self>, R::Searcher: ReverseSearcher<'a, Self> {
+        let left_idx = left_trim_idx(&mut left_pat.into_searcher(self), left)
+            .map_err(|error| error.haystack())?;
+        let right_idx = right_trim_idx(&mut right_pat.into_searcher(self), right)
+            .map_err(|error| error.haystack())?;
+        if left_idx > right_idx {
+            return Err(self);
+        }
+        unsafe { Ok(self.slice_unchecked(left_idx, right_idx)) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from both its sides as many times as possible,
+    /// but the same number of times on each side. Also returns that number.
+    /// The matches trimmed from both the sides never overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!((2, "bold"), "**bold**".unwrap_symmetric('*'));
+    /// assert_eq!((1, "*italic"), "**italic*".unwrap_symmetric('*'));
+    /// assert_eq!((1, "a"), "aaa".unwrap_symmetric('a'));
+    /// assert_eq!((0, "text"), "text".unwrap_symmetric('*'));
+    /// ```
+    fn unwrap_symmetric<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P) -> (usize, &'a Self)
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let mut matcher = pat.into_searcher(self);
+        let mut left_idx = 0;
+        let mut right_idx = self.len();
+        let mut count = 0;
+        while let SearchStep::Match(_, match_end) = matcher.next() {
+            let match_start = match matcher.next_back() {
+                SearchStep::Match(match_start, _) => match_start,
+                _ => break,
+            };
+            if match_end > match_start {
+                break;
             }
-            Ok(self.slice_unchecked(trim_idx, self.len()))
+            left_idx = match_end;
+            right_idx = match_start;
+            count += 1;
         }
+        unsafe { (count, self.slice_unchecked(left_idx, right_idx)) }
     }
 
-    fn trim_right_matches_exactly<'a, P: Pattern<'a>>(&'a self, pat: P, count: usize)
-            -> Result<&'a str, &'a str>
-            where P::Searcher: ReverseSearcher<'a> {
+    /// Returns '&str' with pattern matches trimmed from both its sides given number of times.
+    /// If pattern can't be trimmed off that many times or if the matches trimmed from both
+    /// the sides would overlap, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("raw"), r####"##"raw"##"####.unwrap_symmetric_exactly('#', 2)
+    ///     .and_then(|inner| inner.unwrap_symmetric_exactly('"', 1)));
+    /// assert_eq!(Err("*italic*"), "*italic*".unwrap_symmetric_exactly('*', 2));
+    /// ```
+    fn unwrap_symmetric_exactly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, count: usize)
+            -> Result<&'a Self, &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        self.trim_matches_exactly(pat, count, count)
+    }
+
+    /// Returns '&str' with all pattern matches trimmed from both its sides and their number.
+    /// If pattern matches different number of times on each side, returns `Err` with
+    /// an untrimmed `&str`. If the matches cover the whole '&str', they are split evenly
+    /// between the sides, which fails if their number is odd.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok((2, "bold")), "**bold**".unwrap_symmetric_strictly('*'));
+    /// assert_eq!(Err("**italic*"), "**italic*".unwrap_symmetric_strictly('*'));
+    /// assert_eq!(Ok((1, "")), "\"\"".unwrap_symmetric_strictly('"'));
+    /// assert_eq!(Err("aaa"), "aaa".unwrap_symmetric_strictly('a'));
+    /// ```
+    fn unwrap_symmetric_strictly<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P)
+            -> Result<(usize, &'a Self), &'a Self>
+            where P::Searcher: ReverseSearcher<'a, Self> {
         let mut matcher = pat.into_searcher(self);
-        unsafe {
-            let mut trim_idx = self.len();
-            for _ in 0..count {
+        let (mut left_idx, mut left_count, mut left_done) = (0, 0, false);
+        let (mut right_idx, mut right_count, mut right_done) = (self.len(), 0, false);
+        while !left_done || !right_done {
+            if !left_done {
+                match matcher.next() {
+                    SearchStep::Match(_, match_end) if match_end <= right_idx => {
+                        left_idx = match_end;
+                        left_count += 1;
+                    },
+                    _ => left_done = true,
+                }
+            }
+            if !right_done {
                 match matcher.next_back() {
-                    SearchStep::Match(match_start, _) => trim_idx = match_start,
-                    _ => return Err(self),
+                    SearchStep::Match(match_start, _) if match_start >= left_idx => {
+                        right_idx = match_start;
+                        right_count += 1;
+                    },
+                    _ => right_done = true,
                 }
             }
-            Ok(self.slice_unchecked(0, trim_idx))
         }
+        if left_count != right_count {
+            return Err(self);
+        }
+        unsafe { Ok((left_count, self.slice_unchecked(left_idx, right_idx))) }
+    }
+
+    /// Counts how many times pattern matches in a row at the beginning of '&str' without trimming.
+    /// Stops counting after reaching the limit, if it's given.
+    /// Returns the number of matches and the byte offset, at which the last match ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!((3, 3), ">>> quote".count_left_matches('>', None));
+    /// assert_eq!((2, 2), ">>> quote".count_left_matches('>', Some(2)));
+    /// assert_eq!((0, 0), "text".count_left_matches('>', None));
+    /// ```
+    fn count_left_matches<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, limit: Option<usize>)
+            -> (usize, usize) {
+        let mut matcher = pat.into_searcher(self);
+        let mut count = 0;
+        let mut match_idx = 0;
+        while limit.is_none_or(|limit| count < limit) {
+            match matcher.next() {
+                SearchStep::Match(_, match_end) => match_idx = match_end,
+                _ => break,
+            }
+            count += 1;
+        }
+        (count, match_idx)
+    }
+
+    /// Counts how many times pattern matches in a row at the end of '&str' without trimming.
+    /// Stops counting after reaching the limit, if it's given.
+    /// Returns the number of matches and the byte offset, at which the last match starts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!((2, 4), "text\n\n".count_right_matches('\n', None));
+    /// assert_eq!((1, 5), "text\n\n".count_right_matches('\n', Some(1)));
+    /// ```
+    fn count_right_matches<'a, P: ExactPattern<'a, Self>>(&'a self, pat: P, limit: Option<usize>)
+            -> (usize, usize)
+            where P::Searcher: ReverseSearcher<'a, Self> {
+        let mut matcher = pat.into_searcher(self);
+        let mut count = 0;
+        let mut match_idx = self.len();
+        while limit.is_none_or(|limit| count < limit) {
+            match matcher.next_back() {
+                SearchStep::Match(match_start, _) => match_idx = match_start,
+                _ => break,
+            }
+            count += 1;
+        }
+        (count, match_idx)
+    }
+
+    /// Returns '&str' with a sequence of patterns trimmed from its beginning, each one given number of times.
+    /// The sequence is a tuple of up to 8 `(pattern, count)` steps or an array of them.
+    /// If any step fails, returns a [`SequenceError`](struct.SequenceError.html)
+    /// with an untrimmed `&str` and the index of the failed step.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("doc"), "//! doc".trim_left_sequence_exactly((("//", 1), ('!', 1), (' ', 1))));
+    /// assert_eq!(Ok("doc"), "//  doc".trim_left_sequence_exactly([('/', 2), (' ', 2)]));
+    ///
+    /// let error = "// doc".trim_left_sequence_exactly((("//", 1), ('!', 1), (' ', 1))).unwrap_err();
+    /// assert_eq!("// doc", error.haystack());
+    /// assert_eq!(1, error.step());
+    /// assert_eq!(2, error.error().position());
+    /// ```
+    fn trim_left_sequence_exactly<'a, S: Sequence<'a, Self>>(&'a self, steps: S)
+            -> Result<&'a Self, SequenceError<'a, Self>> {
+        let trim_idx = steps.trim_left_idx(self)?;
+        unsafe { Ok(self.slice_unchecked(trim_idx, self.len())) }
+    }
+
+    /// Returns '&str' with a sequence of patterns trimmed from its end, each one given number of times.
+    /// The steps are trimmed in order, the first one is the closest to the end.
+    /// If any step fails, returns a [`SequenceError`](struct.SequenceError.html)
+    /// with an untrimmed `&str` and the index of the failed step.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("/* text"), "/* text */".trim_right_sequence_exactly((("*/", 1), (' ', 1))));
+    ///
+    /// let error = "/* text*/".trim_right_sequence_exactly((("*/", 1), (' ', 1))).unwrap_err();
+    /// assert_eq!(1, error.step());
+    /// ```
+    fn trim_right_sequence_exactly<'a, S: ReverseSequence<'a, Self>>(&'a self, steps: S)
+            -> Result<&'a Self, SequenceError<'a, Self>> {
+        let trim_idx = steps.trim_right_idx(self)?;
+        unsafe { Ok(self.slice_unchecked(0, trim_idx)) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its beginning given number of times.
+    /// The pattern for each step is created by the closure from the index of the step,
+    /// then it's matched once right after the previous match.
+    /// If any pattern doesn't match, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("text"), "-+-+text".trim_left_with(4, |i| ['-', '+'][i % 2]));
+    /// assert_eq!(Err("--+text"), "--+text".trim_left_with(3, |i| ['-', '+'][i % 2]));
+    /// assert_eq!(Ok("text"), "1.2.3.text".trim_left_with(3, |i| ["1.", "2.", "3."][i]));
+    /// ```
+    fn trim_left_with<'a, P, F>(&'a self, count: usize, mut patterns: F) -> Result<&'a Self, &'a Self>
+            where P: ExactPattern<'a, Self>, F: FnMut(usize) -> P {
+        let mut trim_idx = 0;
+        for step in 0..count {
+            let rest = unsafe { self.slice_unchecked(trim_idx, self.len()) };
+            match patterns(step).into_searcher(rest).next() {
+                SearchStep::Match(0, match_end) => trim_idx += match_end,
+                _ => return Err(self),
+            }
+        }
+        unsafe { Ok(self.slice_unchecked(trim_idx, self.len())) }
+    }
+
+    /// Returns '&str' with pattern matches trimmed from its end given number of times.
+    /// The pattern for each step is created by the closure from the index of the step,
+    /// then it's matched once right before the previous match.
+    /// If any pattern doesn't match, returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::TrimMatchesExactlyExt;
+    /// assert_eq!(Ok("text"), "text+-+-".trim_right_with(4, |i| ['-', '+'][i % 2]));
+    /// assert_eq!(Err("text+--"), "text+--".trim_right_with(3, |i| ['-', '+'][i % 2]));
+    /// ```
+    fn trim_right_with<'a, P, F>(&'a self, count: usize, mut patterns: F) -> Result<&'a Self, &'a Self>
+            where P: ExactPattern<'a, Self>, P::Searcher: ReverseSearcher<'a, Self>, F: FnMut(usize) -> P {
+        let mut trim_idx = self.len();
+        for step in 0..count {
+            let rest = unsafe { self.slice_unchecked(0, trim_idx) };
+            match patterns(step).into_searcher(rest).next_back() {
+                SearchStep::Match(match_start, match_end) if match_end == trim_idx => trim_idx = match_start,
+                _ => return Err(self),
+            }
+        }
+        unsafe { Ok(self.slice_unchecked(0, trim_idx)) }
     }
 }
 
+impl TrimMatchesExactlyExt for str {}
+
+impl TrimMatchesExactlyExt for [u8] {}
+
+#[cfg(all(feature = "std", unix))]
+impl TrimMatchesExactlyExt for std::ffi::OsStr {}
+
+/// Steps the searcher forward given number of times, returns the end of the last match
+fn left_trim_idx<'a, H, S>(matcher: &mut S, count: usize) -> Result<usize, TrimError<'a, H>>
+        where H: ?Sized + Haystack, S: Searcher<'a, H> {
+    let mut trim_idx = 0;
+    for matches in 0..count {
+        match matcher.next() {
+            SearchStep::Match(_, match_end) => trim_idx = match_end,
+            SearchStep::Reject(reject_start, _) =>
+                return Err(TrimError::new(matcher.haystack(), matches, reject_start, TrimErrorKind::Mismatch)),
+            SearchStep::Done =>
+                return Err(TrimError::new(matcher.haystack(), matches, trim_idx, TrimErrorKind::InputEnded)),
+        }
+    }
+    Ok(trim_idx)
+}
+
+/// Steps the searcher backward given number of times, returns the start of the last match
+fn right_trim_idx<'a, H, S>(matcher: &mut S, count: usize) -> Result<usize, TrimError<'a, H>>
+        where H: ?Sized + Haystack, S: ReverseSearcher<'a, H> {
+    let mut trim_idx = matcher.haystack().len();
+    for matches in 0..count {
+        match matcher.next_back() {
+            SearchStep::Match(match_start, _) => trim_idx = match_start,
+            SearchStep::Reject(_, reject_end) =>
+                return Err(TrimError::new(matcher.haystack(), matches, reject_end, TrimErrorKind::Mismatch)),
+            SearchStep::Done =>
+                return Err(TrimError::new(matcher.haystack(), matches, trim_idx, TrimErrorKind::InputEnded)),
+        }
+    }
+    Ok(trim_idx)
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -138,4 +668,412 @@ mod tests {
             assert_trim(Err("baa"), "baa", "b", 1);
         }
     }
+
+    mod trim_left_matches_quantified {
+        use super::*;
+
+        fn assert_trim(expected: Result<(&str, usize), &str>, haystack: &str, needle: &str,
+                quantifier: Quantifier) {
+            let actual = haystack.trim_left_matches_quantified(needle, quantifier);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and quantifier '{:?}'", haystack, needle, quantifier);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok(("aab", 1)), "aab", "",  Quantifier::AtLeast(0));
+            assert_trim(Err("aab"),     "aab", "",  Quantifier::AtLeast(2));
+            assert_trim(Ok(("b", 2)),   "aab", "a", Quantifier::Exactly(2));
+            assert_trim(Ok(("ab", 1)),  "aab", "a", Quantifier::AtMost(1));
+            assert_trim(Ok(("b", 2)),   "aab", "a", Quantifier::AtMost(3));
+            assert_trim(Ok(("b", 2)),   "aab", "a", Quantifier::AtLeast(2));
+            assert_trim(Err("aab"),     "aab", "a", Quantifier::AtLeast(3));
+            assert_trim(Ok(("b", 2)),   "aab", "a", Quantifier::Between(1, 3));
+            assert_trim(Err("aab"),     "aab", "a", Quantifier::Between(3, 4));
+            assert_trim(Ok(("aab", 0)), "aab", "b", Quantifier::AtMost(1));
+        }
+    }
+
+    mod trim_right_matches_quantified {
+        use super::*;
+
+        fn assert_trim(expected: Result<(&str, usize), &str>, haystack: &str, needle: &str,
+                quantifier: Quantifier) {
+            let actual = haystack.trim_right_matches_quantified(needle, quantifier);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and quantifier '{:?}'", haystack, needle, quantifier);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok(("baa", 1)), "baa", "",  Quantifier::AtLeast(0));
+            assert_trim(Err("baa"),     "baa", "",  Quantifier::AtLeast(2));
+            assert_trim(Ok(("b", 2)),   "baa", "a", Quantifier::Exactly(2));
+            assert_trim(Ok(("ba", 1)),  "baa", "a", Quantifier::AtMost(1));
+            assert_trim(Ok(("b", 2)),   "baa", "a", Quantifier::AtMost(3));
+            assert_trim(Ok(("b", 2)),   "baa", "a", Quantifier::AtLeast(2));
+            assert_trim(Err("baa"),     "baa", "a", Quantifier::AtLeast(3));
+            assert_trim(Ok(("b", 2)),   "baa", "a", Quantifier::Between(1, 3));
+            assert_trim(Err("baa"),     "baa", "a", Quantifier::Between(3, 4));
+            assert_trim(Ok(("baa", 0)), "baa", "b", Quantifier::AtMost(1));
+        }
+    }
+
+    mod trim_left_matches_strictly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, needle: &str, count: usize) {
+            let actual = haystack.trim_left_matches_strictly(needle, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Err("aab"), "aab", "",  0);
+            assert_trim(Ok("aab"),  "aab", "",  1);
+            assert_trim(Err("aab"), "aab", "",  2);
+            assert_trim(Err("aab"), "aab", "a", 0);
+            assert_trim(Err("aab"), "aab", "a", 1);
+            assert_trim(Ok("b"),    "aab", "a", 2);
+            assert_trim(Err("aab"), "aab", "a", 3);
+            assert_trim(Ok("aab"),  "aab", "b", 0);
+            assert_trim(Err("aab"), "aab", "b", 1);
+        }
+    }
+
+    mod trim_right_matches_strictly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, needle: &str, count: usize) {
+            let actual = haystack.trim_right_matches_strictly(needle, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Err("baa"), "baa", "",  0);
+            assert_trim(Ok("baa"),  "baa", "",  1);
+            assert_trim(Err("baa"), "baa", "",  2);
+            assert_trim(Err("baa"), "baa", "a", 0);
+            assert_trim(Err("baa"), "baa", "a", 1);
+            assert_trim(Ok("b"),    "baa", "a", 2);
+            assert_trim(Err("baa"), "baa", "a", 3);
+            assert_trim(Ok("baa"),  "baa", "b", 0);
+            assert_trim(Err("baa"), "baa", "b", 1);
+        }
+    }
+
+    mod try_trim_left_matches_exactly {
+        use super::*;
+
+        fn assert_error(haystack: &str, needle: &str, count: usize, matches: usize, position: usize,
+                kind: TrimErrorKind) {
+            let actual = haystack.try_trim_left_matches_exactly(needle, count);
+
+            assert_eq!(Err(TrimError::new(haystack, matches, position, kind)), actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_error_describing_failure() {
+            assert_error("aab", "",  2, 1, 0, TrimErrorKind::Mismatch);
+            assert_error("",    "",  2, 1, 0, TrimErrorKind::InputEnded);
+            assert_error("aab", "a", 3, 2, 2, TrimErrorKind::Mismatch);
+            assert_error("aa",  "a", 3, 2, 2, TrimErrorKind::InputEnded);
+            assert_error("aab", "b", 1, 0, 0, TrimErrorKind::Mismatch);
+        }
+    }
+
+    mod try_trim_right_matches_exactly {
+        use super::*;
+
+        fn assert_error(haystack: &str, needle: &str, count: usize, matches: usize, position: usize,
+                kind: TrimErrorKind) {
+            let actual = haystack.try_trim_right_matches_exactly(needle, count);
+
+            assert_eq!(Err(TrimError::new(haystack, matches, position, kind)), actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_error_describing_failure() {
+            assert_error("baa", "",  2, 1, 3, TrimErrorKind::Mismatch);
+            assert_error("",    "",  2, 1, 0, TrimErrorKind::InputEnded);
+            assert_error("baa", "a", 3, 2, 1, TrimErrorKind::Mismatch);
+            assert_error("aa",  "a", 3, 2, 0, TrimErrorKind::InputEnded);
+            assert_error("baa", "b", 1, 0, 3, TrimErrorKind::Mismatch);
+        }
+    }
+
+    mod split_left_matches_exactly {
+        use super::*;
+
+        fn assert_split(expected: Result<(&str, &str), &str>, haystack: &str, needle: &str, count: usize) {
+            let actual = haystack.split_left_matches_exactly(needle, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_split_or_original_str() {
+            assert_split(Ok(("", "aab")),  "aab", "",  1);
+            assert_split(Err("aab"),       "aab", "",  2);
+            assert_split(Ok(("", "aab")),  "aab", "a", 0);
+            assert_split(Ok(("aa", "b")),  "aab", "a", 2);
+            assert_split(Err("aab"),       "aab", "a", 3);
+            assert_split(Err("aab"),       "aab", "b", 1);
+        }
+    }
+
+    mod split_right_matches_exactly {
+        use super::*;
+
+        fn assert_split(expected: Result<(&str, &str), &str>, haystack: &str, needle: &str, count: usize) {
+            let actual = haystack.split_right_matches_exactly(needle, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and count '{}'", haystack, needle, count);
+        }
+
+        #[test]
+        fn returns_split_or_original_str() {
+            assert_split(Ok(("baa", "")),  "baa", "",  1);
+            assert_split(Err("baa"),       "baa", "",  2);
+            assert_split(Ok(("baa", "")),  "baa", "a", 0);
+            assert_split(Ok(("b", "aa")),  "baa", "a", 2);
+            assert_split(Err("baa"),       "baa", "a", 3);
+            assert_split(Err("baa"),       "baa", "b", 1);
+        }
+    }
+
+    mod trim_matches_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, needle: &str, left: usize, right: usize) {
+            let actual = haystack.trim_matches_exactly(needle, left, right);
+
+            assert_eq!(expected, actual, "For haystack '{}', needle '{}' and counts '{}' and '{}'",
+                haystack, needle, left, right);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("aba"),  "aba", "",   1, 1);
+            assert_trim(Err("aba"), "aba", "",   2, 0);
+            assert_trim(Ok("b"),    "aba", "a",  1, 1);
+            assert_trim(Err("aba"), "aba", "a",  2, 1);
+            assert_trim(Ok(""),     "aa",  "a",  1, 1);
+            assert_trim(Ok(""),     "aa",  "a",  2, 0);
+            assert_trim(Err("aa"),  "aa",  "a",  2, 1);
+            assert_trim(Err("aaa"), "aaa", "aa", 1, 1);
+        }
+    }
+
+    mod trim_both_matches_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, left_needle: &str, left: usize,
+                right_needle: &str, right: usize) {
+            let actual = haystack.trim_both_matches_exactly(left_needle, left, right_needle, right);
+
+            assert_eq!(expected, actual, "For haystack '{}', needles '{}' and '{}' and counts '{}' and '{}'",
+                haystack, left_needle, right_needle, left, right);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("b"),    "abc", "a",  1, "c",  1);
+            assert_trim(Ok(""),     "abc", "ab", 1, "c",  1);
+            assert_trim(Err("abc"), "abc", "ab", 1, "bc", 1);
+            assert_trim(Err("abc"), "abc", "a",  2, "c",  1);
+            assert_trim(Err("abc"), "abc", "a",  1, "c",  2);
+            assert_trim(Ok("abc"),  "abc", "b",  0, "b",  0);
+        }
+    }
+
+    mod unwrap_symmetric {
+        use super::*;
+
+        fn assert_unwrap(expected: (usize, &str), haystack: &str, needle: &str) {
+            let actual = haystack.unwrap_symmetric(needle);
+
+            assert_eq!(expected, actual, "For haystack '{}' and needle '{}'", haystack, needle);
+        }
+
+        #[test]
+        fn returns_unwrapped_str() {
+            assert_unwrap((1, "aba"), "aba",   "");
+            assert_unwrap((1, "b"),   "aba",   "a");
+            assert_unwrap((1, "ab"),  "aaba",  "a");
+            assert_unwrap((2, "b"),   "aabaa", "a");
+            assert_unwrap((1, ""),    "aa",    "a");
+            assert_unwrap((1, "a"),   "aaa",   "a");
+            assert_unwrap((0, "aaa"), "aaa",   "aa");
+            assert_unwrap((0, "aba"), "aba",   "b");
+        }
+    }
+
+    mod unwrap_symmetric_strictly {
+        use super::*;
+
+        fn assert_unwrap(expected: Result<(usize, &str), &str>, haystack: &str, needle: &str) {
+            let actual = haystack.unwrap_symmetric_strictly(needle);
+
+            assert_eq!(expected, actual, "For haystack '{}' and needle '{}'", haystack, needle);
+        }
+
+        #[test]
+        fn returns_unwrapped_or_original_str() {
+            assert_unwrap(Ok((1, "b")),   "aba",   "a");
+            assert_unwrap(Err("aaba"),    "aaba",  "a");
+            assert_unwrap(Ok((2, "b")),   "aabaa", "a");
+            assert_unwrap(Ok((1, "")),    "aa",    "a");
+            assert_unwrap(Err("aaa"),     "aaa",   "a");
+            assert_unwrap(Ok((2, "")),    "aaaa",  "a");
+            assert_unwrap(Ok((0, "")),    "",      "a");
+            assert_unwrap(Ok((1, "b")),   "abbab", "ab");
+            assert_unwrap(Ok((0, "aba")), "aba",   "b");
+        }
+    }
+
+    mod count_left_matches {
+        use super::*;
+
+        fn assert_count(expected: (usize, usize), haystack: &str, needle: &str, limit: Option<usize>) {
+            let actual = haystack.count_left_matches(needle, limit);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and limit '{:?}'", haystack, needle, limit);
+        }
+
+        #[test]
+        fn returns_count_and_offset() {
+            assert_count((1, 0), "aab", "",   None);
+            assert_count((0, 0), "aab", "",   Some(0));
+            assert_count((2, 2), "aab", "a",  None);
+            assert_count((1, 1), "aab", "a",  Some(1));
+            assert_count((2, 2), "aab", "a",  Some(3));
+            assert_count((1, 2), "aab", "aa", None);
+            assert_count((0, 0), "aab", "b",  None);
+        }
+    }
+
+    mod count_right_matches {
+        use super::*;
+
+        fn assert_count(expected: (usize, usize), haystack: &str, needle: &str, limit: Option<usize>) {
+            let actual = haystack.count_right_matches(needle, limit);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needle '{}' and limit '{:?}'", haystack, needle, limit);
+        }
+
+        #[test]
+        fn returns_count_and_offset() {
+            assert_count((1, 3), "baa", "",   None);
+            assert_count((0, 3), "baa", "",   Some(0));
+            assert_count((2, 1), "baa", "a",  None);
+            assert_count((1, 2), "baa", "a",  Some(1));
+            assert_count((2, 1), "baa", "a",  Some(3));
+            assert_count((1, 1), "baa", "aa", None);
+            assert_count((0, 3), "baa", "b",  None);
+        }
+    }
+
+    mod trim_left_sequence_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, (usize, usize)>, haystack: &str, first: &str, second: &str) {
+            let actual = haystack.trim_left_sequence_exactly(((first, 1), (second, 2)))
+                .map_err(|error| (error.step(), error.error().position()));
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', first needle '{}' and second needle '{}'", haystack, first, second);
+        }
+
+        #[test]
+        fn returns_trimmed_str_or_failed_step() {
+            assert_trim(Ok("b"),     "aab",  "",  "a");
+            assert_trim(Ok("b"),     "aaab", "a", "a");
+            assert_trim(Err((1, 2)), "aab",  "a", "a");
+            assert_trim(Err((1, 2)), "aa",   "a", "a");
+            assert_trim(Err((0, 0)), "aab",  "b", "a");
+            assert_trim(Err((1, 0)), "aab",  "",  "");
+        }
+    }
+
+    mod trim_right_sequence_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, (usize, usize)>, haystack: &str, first: &str, second: &str) {
+            let actual = haystack.trim_right_sequence_exactly(((first, 1), (second, 2)))
+                .map_err(|error| (error.step(), error.error().position()));
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', first needle '{}' and second needle '{}'", haystack, first, second);
+        }
+
+        #[test]
+        fn returns_trimmed_str_or_failed_step() {
+            assert_trim(Ok("b"),     "baa",  "",  "a");
+            assert_trim(Ok("b"),     "baaa", "a", "a");
+            assert_trim(Err((1, 1)), "baa",  "a", "a");
+            assert_trim(Err((1, 0)), "aa",   "a", "a");
+            assert_trim(Err((0, 3)), "baa",  "b", "a");
+            assert_trim(Err((1, 3)), "baa",  "",  "");
+        }
+    }
+
+    mod trim_left_with {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, needles: &[&str], count: usize) {
+            let actual = haystack.trim_left_with(count, |step| needles[step % needles.len()]);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needles '{:?}' and count '{}'", haystack, needles, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("aab"),   "aab", &[""],         2);
+            assert_trim(Ok("ab"),    "aab", &["a", ""],    2);
+            assert_trim(Ok(""),      "aab", &["a", "ab"],  2);
+            assert_trim(Err("aab"),  "aab", &["a", "b"],   2);
+            assert_trim(Ok("b"),     "aab", &["a"],        2);
+            assert_trim(Err("aab"),  "aab", &["a"],        3);
+            assert_trim(Ok("aab"),   "aab", &["b"],        0);
+            assert_trim(Err("aab"),  "aab", &["b"],        1);
+        }
+    }
+
+    mod trim_right_with {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, needles: &[&str], count: usize) {
+            let actual = haystack.trim_right_with(count, |step| needles[step % needles.len()]);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', needles '{:?}' and count '{}'", haystack, needles, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("baa"),   "baa", &[""],         2);
+            assert_trim(Ok("ba"),    "baa", &["a", ""],    2);
+            assert_trim(Ok(""),      "baa", &["a", "ba"],  2);
+            assert_trim(Err("baa"),  "baa", &["a", "b"],   2);
+            assert_trim(Ok("b"),     "baa", &["a"],        2);
+            assert_trim(Err("baa"),  "baa", &["a"],        3);
+            assert_trim(Ok("baa"),   "baa", &["b"],        0);
+            assert_trim(Err("baa"),  "baa", &["b"],        1);
+        }
+    }
 }
@@ -0,0 +1,154 @@
+use core::error::Error;
+use alloc::vec::{self, Vec};
+use core::fmt::{self, Display, Formatter};
+
+use pattern::ExactPattern;
+use TrimMatchesExactlyExt;
+
+/// The policy for lines containing only whitespace
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BlankLines {
+    /// Blank lines are returned untrimmed
+    Skip,
+    /// Blank lines must be trimmed like all the other lines
+    Require,
+}
+
+/// The error returned when a pattern can't be trimmed off a line
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct LineError<'a> {
+    number: usize,
+    line: &'a str,
+}
+
+impl<'a> LineError<'a> {
+    /// Returns the number of the line, counting from 1
+    pub fn line_number(&self) -> usize {
+        self.number
+    }
+
+    /// Returns the untrimmed line
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+}
+
+impl<'a> Display for LineError<'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "pattern can't be trimmed off line {}", self.number)
+    }
+}
+
+impl<'a> Error for LineError<'a> {}
+
+/// The iterator over trimmed lines returned by
+/// [`trim_lines_left_matches_exactly`](trait.TrimLinesExactlyExt.html#tymethod.trim_lines_left_matches_exactly).
+#[derive(Clone, Debug)]
+pub struct TrimmedLines<'a> {
+    lines: vec::IntoIter<&'a str>,
+}
+
+impl<'a> Iterator for TrimmedLines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.lines.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+fn trim_line<'a, P: ExactPattern<'a>>(line: &'a str, pat: P, count: usize, blank_lines: BlankLines)
+        -> Result<&'a str, &'a str> {
+    if blank_lines == BlankLines::Skip && line.trim().is_empty() {
+        return Ok(line);
+    }
+    line.trim_left_matches_exactly(pat, count)
+}
+
+/// The extension trait adding methods for trimming every line of `str`.
+///
+/// It's available with the `alloc` feature.
+pub trait TrimLinesExactlyExt {
+    /// Returns an iterator over lines of '&str' with pattern matches trimmed from their beginnings
+    /// given number of times. If pattern can't be trimmed off any line that many times,
+    /// returns a [`LineError`](struct.LineError.html) describing the first such line.
+    /// Lines are split like with `str::lines`. The pattern is cloned and run once for each line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{BlankLines, TrimLinesExactlyExt};
+    /// let reply = "> > Hi\n> >\n> > Bye";
+    /// let lines: Vec<_> = reply.trim_lines_left_matches_exactly("> ", 1, BlankLines::Skip).unwrap().collect();
+    /// assert_eq!(vec!["> Hi", ">", "> Bye"], lines);
+    ///
+    /// let code = "    a();\n\n    b();";
+    /// let lines: Vec<_> = code.trim_lines_left_matches_exactly(' ', 4, BlankLines::Skip).unwrap().collect();
+    /// assert_eq!(vec!["a();", "", "b();"], lines);
+    /// let error = code.trim_lines_left_matches_exactly(' ', 4, BlankLines::Require).unwrap_err();
+    /// assert_eq!(2, error.line_number());
+    /// ```
+    fn trim_lines_left_matches_exactly<'a, P>(&'a self, pat: P, count: usize, blank_lines: BlankLines)
+        -> Result<TrimmedLines<'a>, LineError<'a>>
+        where P: ExactPattern<'a> + Clone;
+}
+
+impl TrimLinesExactlyExt for str {
+    fn trim_lines_left_matches_exactly<'a, P>(&'a self, pat: P, count: usize, blank_lines: BlankLines)
+            -> Result<TrimmedLines<'a>, LineError<'a>>
+            where P: ExactPattern<'a> + Clone {
+        let mut trimmed_lines = Vec::new();
+        for (idx, line) in self.lines().enumerate() {
+            match trim_line(line, pat.clone(), count, blank_lines) {
+                Ok(trimmed) => trimmed_lines.push(trimmed),
+                Err(line) => return Err(LineError { number: idx + 1, line }),
+            }
+        }
+        Ok(TrimmedLines { lines: trimmed_lines.into_iter() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_trim(expected: Result<&[&str], usize>, haystack: &str, needle: &str, count: usize,
+            blank_lines: BlankLines) {
+        let actual = haystack.trim_lines_left_matches_exactly(needle, count, blank_lines);
+
+        let matches = match (expected, actual) {
+            (Ok(expected), Ok(actual)) => actual.eq(expected.iter().cloned()),
+            (Err(expected), Err(actual)) => expected == actual.line_number(),
+            _ => false,
+        };
+        assert!(matches, "For haystack '{:?}', needle '{}' and count '{}'", haystack, needle, count);
+    }
+
+    #[test]
+    fn returns_trimmed_lines_or_first_failed_line() {
+        assert_trim(Ok(&["b", "", "b"]),  "aab\n\naab",    "a", 2, BlankLines::Skip);
+        assert_trim(Err(2),               "aab\n\naab",    "a", 2, BlankLines::Require);
+        assert_trim(Ok(&["b", "", "b"]),  "aab\naa\naab",  "a", 2, BlankLines::Require);
+        assert_trim(Err(3),               "aab\naa\nab",   "a", 2, BlankLines::Skip);
+        assert_trim(Ok(&["b", " ", "b"]), "aab\n \r\naab", "a", 2, BlankLines::Skip);
+        assert_trim(Ok(&[]),              "",              "a", 2, BlankLines::Require);
+    }
+
+    #[test]
+    fn runs_pattern_once_per_line() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let pat = |c: char| {
+            calls.set(calls.get() + 1);
+            c == 'a' && calls.get() <= 2
+        };
+        let lines = "ab
+ab".trim_lines_left_matches_exactly(pat, 1, BlankLines::Require).unwrap();
+
+        assert!(lines.eq(["b", "b"].iter().cloned()));
+        assert_eq!(2, calls.get());
+    }
+}
@@ -0,0 +1,113 @@
+use std::path::{Component, Path};
+
+/// The extension trait adding methods for controlled stripping of `Path` components
+pub trait StripComponentsExactlyExt {
+    /// Returns `&Path` with given number of components stripped from its beginning,
+    /// like `tar --strip-components`. The root and the prefix of an absolute path are stripped
+    /// together with the first component and aren't counted.
+    /// If the path has fewer components, returns `Err` with an unstripped `&Path`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::path::Path;
+    /// # use trim_matches_exactly::StripComponentsExactlyExt;
+    /// let path = Path::new("/usr/lib/libc.so");
+    /// assert_eq!(Ok(Path::new("lib/libc.so")), path.strip_left_components_exactly(1));
+    /// assert_eq!(Ok(Path::new("")), path.strip_left_components_exactly(3));
+    /// assert_eq!(Err(path), path.strip_left_components_exactly(4));
+    /// ```
+    fn strip_left_components_exactly(&self, count: usize) -> Result<&Path, &Path>;
+
+    /// Returns `&Path` with given number of components stripped from its end.
+    /// The root and the prefix of an absolute path can't be stripped.
+    /// If the path has fewer components, returns `Err` with an unstripped `&Path`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::path::Path;
+    /// # use trim_matches_exactly::StripComponentsExactlyExt;
+    /// let path = Path::new("/usr/lib/libc.so");
+    /// assert_eq!(Ok(Path::new("/usr")), path.strip_right_components_exactly(2));
+    /// assert_eq!(Ok(Path::new("/")), path.strip_right_components_exactly(3));
+    /// assert_eq!(Err(path), path.strip_right_components_exactly(4));
+    /// ```
+    fn strip_right_components_exactly(&self, count: usize) -> Result<&Path, &Path>;
+}
+
+impl StripComponentsExactlyExt for Path {
+    fn strip_left_components_exactly(&self, count: usize) -> Result<&Path, &Path> {
+        let mut components = self.components();
+        let mut stripped = 0;
+        while stripped < count {
+            match components.next() {
+                Some(Component::Prefix(_)) | Some(Component::RootDir) => (),
+                Some(_) => stripped += 1,
+                None => return Err(self),
+            }
+        }
+        Ok(components.as_path())
+    }
+
+    fn strip_right_components_exactly(&self, count: usize) -> Result<&Path, &Path> {
+        let mut components = self.components();
+        for _ in 0..count {
+            match components.next_back() {
+                Some(Component::Prefix(_)) | Some(Component::RootDir) | None => return Err(self),
+                Some(_) => (),
+            }
+        }
+        Ok(components.as_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod strip_left_components_exactly {
+        use super::*;
+
+        fn assert_strip(expected: Result<&str, &str>, path: &str, count: usize) {
+            let actual = Path::new(path).strip_left_components_exactly(count);
+
+            assert_eq!(expected.map(Path::new).map_err(Path::new), actual,
+                "For path '{}' and count '{}'", path, count);
+        }
+
+        #[test]
+        fn returns_stripped_or_original_path() {
+            assert_strip(Ok("a/b"),  "a/b",   0);
+            assert_strip(Ok("b"),    "a/b",   1);
+            assert_strip(Ok(""),     "a/b",   2);
+            assert_strip(Err("a/b"), "a/b",   3);
+            assert_strip(Ok("/a/b"), "/a/b",  0);
+            assert_strip(Ok("b"),    "/a/b",  1);
+            assert_strip(Err("/a"),  "/a",    2);
+            assert_strip(Ok("a/b/"), "./a/b/", 1);
+            assert_strip(Ok("b"),    "../b",  1);
+        }
+    }
+
+    mod strip_right_components_exactly {
+        use super::*;
+
+        fn assert_strip(expected: Result<&str, &str>, path: &str, count: usize) {
+            let actual = Path::new(path).strip_right_components_exactly(count);
+
+            assert_eq!(expected.map(Path::new).map_err(Path::new), actual,
+                "For path '{}' and count '{}'", path, count);
+        }
+
+        #[test]
+        fn returns_stripped_or_original_path() {
+            assert_strip(Ok("a/b"),  "a/b",   0);
+            assert_strip(Ok("a"),    "a/b/",  1);
+            assert_strip(Ok(""),     "a/b",   2);
+            assert_strip(Err("a/b"), "a/b",   3);
+            assert_strip(Ok("/a"),   "/a/b",  1);
+            assert_strip(Ok("/"),    "/a/b",  2);
+            assert_strip(Err("/a"),  "/a",    2);
+            assert_strip(Ok("."),    "./a",   1);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Patterns for `[u8]`
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern};
+
+fn match_byte_prefix<F: FnMut(u8) -> bool>(haystack: &[u8], mut f: F) -> Option<usize> {
+    haystack.first().filter(|&&b| f(b)).map(|_| 1)
+}
+
+fn match_byte_suffix<F: FnMut(u8) -> bool>(haystack: &[u8], mut f: F) -> Option<usize> {
+    haystack.last().filter(|&&b| f(b)).map(|_| 1)
+}
+
+impl AnchoredPattern<[u8]> for u8 {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_prefix(haystack, |b| b == *self)
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_suffix(haystack, |b| b == *self)
+    }
+}
+
+impl AnchoredPattern<[u8]> for &[u8] {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        if haystack.starts_with(self) { Some(self.len()) } else { None }
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        if haystack.ends_with(self) { Some(self.len()) } else { None }
+    }
+}
+
+impl<const N: usize> AnchoredPattern<[u8]> for &[u8; N] {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        (&self[..]).match_prefix(haystack)
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        (&self[..]).match_suffix(haystack)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AnchoredPattern<[u8]> for &Vec<u8> {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.as_slice().match_prefix(haystack)
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.as_slice().match_suffix(haystack)
+    }
+}
+
+impl<const N: usize> AnchoredPattern<[u8]> for [u8; N] {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_prefix(haystack, |b| self.contains(&b))
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_suffix(haystack, |b| self.contains(&b))
+    }
+}
+
+impl<F: FnMut(u8) -> bool> AnchoredPattern<[u8]> for F {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_prefix(haystack, self)
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        match_byte_suffix(haystack, self)
+    }
+}
+
+anchored_exact_pattern! { [u8];
+    [] u8,
+    ['b] &'b [u8],
+    ['b, const N: usize] &'b [u8; N],
+    [const N: usize] [u8; N],
+    [F: FnMut(u8) -> bool] F,
+}
+
+#[cfg(feature = "alloc")]
+anchored_exact_pattern! { [u8];
+    ['b] &'b Vec<u8>,
+}
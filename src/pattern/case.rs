@@ -0,0 +1,141 @@
+//! Case-insensitive patterns
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern};
+
+/// A pattern matching a string ignoring ASCII case. Other chars must be equal.
+///
+/// It can be trimmed off both `str` and `[u8]`.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::AsciiCaseInsensitive;
+/// assert_eq!(Ok("hello"), "Re: RE: re: hello".trim_left_matches_exactly(AsciiCaseInsensitive("re: "), 3));
+/// assert_eq!(Ok(&b"data"[..]), b"dataEND"[..].trim_right_matches_exactly(AsciiCaseInsensitive("end"), 1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AsciiCaseInsensitive<'b>(pub &'b str);
+
+impl<'b> AnchoredPattern<[u8]> for AsciiCaseInsensitive<'b> {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        let len = self.0.len();
+        if haystack.len() >= len && haystack[..len].eq_ignore_ascii_case(self.0.as_bytes()) {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        let len = self.0.len();
+        if haystack.len() >= len && haystack[haystack.len() - len..].eq_ignore_ascii_case(self.0.as_bytes()) {
+            Some(len)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'b> AnchoredPattern for AsciiCaseInsensitive<'b> {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        self.match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        self.match_suffix(haystack.as_bytes())
+    }
+}
+
+/// A pattern matching a string ignoring Unicode case.
+///
+/// Chars are compared after mapping them to upper case and then to lower case,
+/// skipping mappings to multiple chars, which approximates the Unicode simple case folding.
+/// Like in the folding, the Turkish dotless 'ı' and dotted 'İ' only match themselves.
+/// Because every char is folded to a single char, the match can have a different length in bytes
+/// than the pattern.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::CaseInsensitive;
+/// assert_eq!(Ok("hello"), "Re: RE: re: hello".trim_left_matches_exactly(CaseInsensitive("re: "), 3));
+/// assert_eq!(Ok("ΣΟΦΟΣ "), "ΣΟΦΟΣ σοφος".trim_right_matches_exactly(CaseInsensitive("ΣΟΦΟΣ"), 1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CaseInsensitive<'b>(pub &'b str);
+
+fn single_char<I: Iterator<Item = char>>(mut chars: I) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+fn fold_case(c: char) -> char {
+    if c == '\u{130}' || c == '\u{131}' {
+        return c;
+    }
+    let upper = single_char(c.to_uppercase()).unwrap_or(c);
+    single_char(upper.to_lowercase()).unwrap_or(upper)
+}
+
+fn match_folded<I, J>(mut haystack: I, needle: J) -> Option<usize>
+        where I: Iterator<Item = char>, J: Iterator<Item = char> {
+    let mut len = 0;
+    for needle_char in needle {
+        let haystack_char = haystack.next()?;
+        if fold_case(haystack_char) != fold_case(needle_char) {
+            return None;
+        }
+        len += haystack_char.len_utf8();
+    }
+    Some(len)
+}
+
+impl<'b> AnchoredPattern for CaseInsensitive<'b> {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_folded(haystack.chars(), self.0.chars())
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_folded(haystack.chars().rev(), self.0.chars().rev())
+    }
+}
+
+anchored_exact_pattern! { str;
+    ['b] AsciiCaseInsensitive<'b>,
+    ['b] CaseInsensitive<'b>,
+}
+
+anchored_exact_pattern! { [u8];
+    ['b] AsciiCaseInsensitive<'b>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn ascii_case_insensitive_ignores_ascii_case_only() {
+        assert_eq!(Ok("b"),    "aAb".trim_left_matches_exactly(AsciiCaseInsensitive("a"), 2));
+        assert_eq!(Ok("b"),    "bAa".trim_right_matches_exactly(AsciiCaseInsensitive("A"), 2));
+        assert_eq!(Err("Łb"),  "Łb".trim_left_matches_exactly(AsciiCaseInsensitive("ł"), 1));
+        assert_eq!(Ok("b"),    "Łb".trim_left_matches_exactly(AsciiCaseInsensitive("Ł"), 1));
+        assert_eq!(Err("a"),   "a".trim_left_matches_exactly(AsciiCaseInsensitive("aa"), 1));
+    }
+
+    #[test]
+    fn case_insensitive_folds_unicode_case() {
+        assert_eq!(Ok("b"),    "Łłb".trim_left_matches_exactly(CaseInsensitive("ł"), 2));
+        assert_eq!(Ok("b"),    "bσΣς".trim_right_matches_exactly(CaseInsensitive("ς"), 3));
+        assert_eq!(Ok("b"),    "\u{212A}b".trim_left_matches_exactly(CaseInsensitive("k"), 1));
+        assert_eq!(Ok("b"),    "ßẞb".trim_left_matches_exactly(CaseInsensitive("ß"), 2));
+        assert_eq!(Err("ssb"), "ssb".trim_left_matches_exactly(CaseInsensitive("ß"), 1));
+        assert_eq!(Err("ıx"),  "ıx".trim_left_matches_exactly(CaseInsensitive("i"), 1));
+        assert_eq!(Err("İx"),  "İx".trim_left_matches_exactly(CaseInsensitive("i"), 1));
+        assert_eq!(Err("Ix"),  "Ix".trim_left_matches_exactly(CaseInsensitive("ı"), 1));
+        assert_eq!(Ok("x"),    "ıx".trim_left_matches_exactly(CaseInsensitive("ı"), 1));
+        assert_eq!(Err("a"),   "a".trim_left_matches_exactly(CaseInsensitive("aa"), 1));
+    }
+}
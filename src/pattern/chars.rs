@@ -0,0 +1,102 @@
+//! Patterns for `str`
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::AnchoredPattern;
+#[cfg(not(feature = "nightly"))]
+use super::{AnchoredSearcher, ExactPattern};
+
+fn match_char_prefix<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next().filter(|&c| f(c)).map(char::len_utf8)
+}
+
+fn match_char_suffix<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next_back().filter(|&c| f(c)).map(char::len_utf8)
+}
+
+impl AnchoredPattern for char {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_prefix(haystack, |c| c == *self)
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_suffix(haystack, |c| c == *self)
+    }
+}
+
+impl AnchoredPattern for &str {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.starts_with(*self) { Some(self.len()) } else { None }
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.ends_with(*self) { Some(self.len()) } else { None }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AnchoredPattern for &String {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().match_prefix(haystack)
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().match_suffix(haystack)
+    }
+}
+
+impl AnchoredPattern for &[char] {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_prefix(haystack, |c| self.contains(&c))
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_suffix(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<const N: usize> AnchoredPattern for [char; N] {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_prefix(haystack, |c| self.contains(&c))
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_suffix(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<const N: usize> AnchoredPattern for &[char; N] {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_prefix(haystack, |c| self.contains(&c))
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_suffix(haystack, |c| self.contains(&c))
+    }
+}
+
+impl<F: FnMut(char) -> bool> AnchoredPattern for F {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_prefix(haystack, self)
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        match_char_suffix(haystack, self)
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+anchored_exact_pattern! { str;
+    [] char,
+    ['b] &'b str,
+    ['b] &'b [char],
+    [const N: usize] [char; N],
+    ['b, const N: usize] &'b [char; N],
+    [F: FnMut(char) -> bool] F,
+}
+
+#[cfg(all(feature = "alloc", not(feature = "nightly")))]
+anchored_exact_pattern! { str;
+    ['b] &'b String,
+}
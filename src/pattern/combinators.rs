@@ -0,0 +1,284 @@
+//! Patterns built from other patterns
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern, Haystack};
+
+/// Returns the part of the haystack after the prefix of the given length
+fn after_prefix<H: ?Sized + Haystack>(haystack: &H, len: usize) -> &H {
+    assert!(len <= haystack.len() && haystack.is_boundary(len), "Pattern matched outside of a boundary");
+    unsafe { haystack.slice_unchecked(len, haystack.len()) }
+}
+
+/// Returns the part of the haystack before the suffix of the given length
+fn before_suffix<H: ?Sized + Haystack>(haystack: &H, len: usize) -> &H {
+    assert!(len <= haystack.len() && haystack.is_boundary(haystack.len() - len),
+        "Pattern matched outside of a boundary");
+    unsafe { haystack.slice_unchecked(0, haystack.len() - len) }
+}
+
+impl<H: ?Sized + Haystack> AnchoredPattern<H> for () {
+    fn match_prefix(&mut self, _: &H) -> Option<usize> {
+        Some(0)
+    }
+
+    fn match_suffix(&mut self, _: &H) -> Option<usize> {
+        Some(0)
+    }
+}
+
+/// Implements `AnchoredPattern` for tuples matching their elements one after another.
+/// The indices are listed twice, in order and in reverse.
+macro_rules! tuple_pattern {
+    ($($idx:tt $pat:ident),+; $($rev_idx:tt),+) => {
+        impl<H: ?Sized + Haystack, $($pat: AnchoredPattern<H>),+> AnchoredPattern<H> for ($($pat,)+) {
+            fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+                let mut len = 0;
+                $(len += self.$idx.match_prefix(after_prefix(haystack, len))?;)+
+                Some(len)
+            }
+
+            fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+                let mut len = 0;
+                $(len += self.$rev_idx.match_suffix(before_suffix(haystack, len))?;)+
+                Some(len)
+            }
+        }
+    };
+}
+
+tuple_pattern!(0 A; 0);
+tuple_pattern!(0 A, 1 B; 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C; 2, 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C, 3 D; 3, 2, 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C, 3 D, 4 E; 4, 3, 2, 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F; 5, 4, 3, 2, 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G; 6, 5, 4, 3, 2, 1, 0);
+tuple_pattern!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 I; 7, 6, 5, 4, 3, 2, 1, 0);
+
+/// A pattern matching another pattern given number of times in a row, optionally with a separator
+/// between the repetitions. The whole run is a single match.
+///
+/// The repeated pattern can be a tuple of up to 8 patterns, which are matched one after another.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Repeat;
+/// assert_eq!(Ok("quote"), "> > quote".trim_left_matches_exactly(Repeat::new(('>', ' '), 2), 1));
+/// assert_eq!(Ok("task"), "- [ ] - [ ] task".trim_left_matches_exactly(Repeat::new(("- ", "[ ] "), 1), 2));
+/// assert_eq!(Ok("1.2."), "1.2.0.0".trim_right_matches_exactly(Repeat::new('0', 2).separated_by('.'), 1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Repeat<P, S = ()> {
+    pattern: P,
+    count: usize,
+    separator: S,
+}
+
+impl<P> Repeat<P> {
+    /// Creates a pattern matching the given pattern given number of times
+    pub fn new(pattern: P, count: usize) -> Self {
+        Repeat { pattern, count, separator: () }
+    }
+}
+
+impl<P, S> Repeat<P, S> {
+    /// Sets the pattern, which must match between the repetitions
+    pub fn separated_by<T>(self, separator: T) -> Repeat<P, T> {
+        Repeat { pattern: self.pattern, count: self.count, separator }
+    }
+}
+
+impl<H: ?Sized + Haystack, P: AnchoredPattern<H>, S: AnchoredPattern<H>> AnchoredPattern<H> for Repeat<P, S> {
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+        let mut len = 0;
+        for repetition in 0..self.count {
+            if repetition != 0 {
+                len += self.separator.match_prefix(after_prefix(haystack, len))?;
+            }
+            len += self.pattern.match_prefix(after_prefix(haystack, len))?;
+        }
+        Some(len)
+    }
+
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+        let mut len = 0;
+        for repetition in 0..self.count {
+            if repetition != 0 {
+                len += self.separator.match_suffix(before_suffix(haystack, len))?;
+            }
+            len += self.pattern.match_suffix(before_suffix(haystack, len))?;
+        }
+        Some(len)
+    }
+}
+
+/// A pattern matching the first of two patterns, which matches, or the second one otherwise
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Or;
+/// assert_eq!(Ok("bold"), "*_bold".trim_left_matches_exactly(Or('*', '_'), 2));
+/// assert_eq!(Err("bold**"), "bold**".trim_right_matches_exactly(Or('*', '_'), 3));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Or<A, B>(pub A, pub B);
+
+impl<H: ?Sized + Haystack, A: AnchoredPattern<H>, B: AnchoredPattern<H>> AnchoredPattern<H> for Or<A, B> {
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+        self.0.match_prefix(haystack).or_else(|| self.1.match_prefix(haystack))
+    }
+
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+        self.0.match_suffix(haystack).or_else(|| self.1.match_suffix(haystack))
+    }
+}
+
+/// A pattern matching a single unit of the haystack, e.g. a `char` of `str`,
+/// at which the given pattern doesn't match
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Not;
+/// assert_eq!(Ok(" word"), "key word".trim_left_matches_exactly(Not(' '), 3));
+/// assert_eq!(Err("key word"), "key word".trim_left_matches_exactly(Not(' '), 4));
+/// assert_eq!(Ok("key "), "key word".trim_right_matches_exactly(Not(' '), 4));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Not<P>(pub P);
+
+impl<H: ?Sized + Haystack, P: AnchoredPattern<H>> AnchoredPattern<H> for Not<P> {
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+        match self.0.match_prefix(haystack) {
+            Some(_) => None,
+            None => haystack.first_unit_len(),
+        }
+    }
+
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+        match self.0.match_suffix(haystack) {
+            Some(_) => None,
+            None => haystack.last_unit_len(),
+        }
+    }
+}
+
+/// A pattern matching two patterns one after another
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Then;
+/// assert_eq!(Ok("item"), "1.2.item".trim_left_matches_exactly(Then(char::is_numeric, '.'), 2));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Then<A, B>(pub A, pub B);
+
+impl<H: ?Sized + Haystack, A: AnchoredPattern<H>, B: AnchoredPattern<H>> AnchoredPattern<H> for Then<A, B> {
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+        let len = self.0.match_prefix(haystack)?;
+        Some(len + self.1.match_prefix(after_prefix(haystack, len))?)
+    }
+
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+        let len = self.1.match_suffix(haystack)?;
+        Some(len + self.0.match_suffix(before_suffix(haystack, len))?)
+    }
+}
+
+/// A pattern matching the longest of the given patterns, which matches.
+/// Every pattern is cloned before matching.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::AnyOf;
+/// assert_eq!(Ok("bold"), "***bold".trim_left_matches_exactly(AnyOf(&["*", "**"]), 2));
+/// assert_eq!(Ok("bold"), "**__bold".trim_left_matches_exactly(AnyOf(&["**", "__"]), 2));
+/// assert_eq!(Ok(&b"data"[..]), b"data\r\n\n"[..].trim_right_matches_exactly(AnyOf(&[&b"\n"[..], b"\r\n"]), 2));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AnyOf<'b, P: 'b>(pub &'b [P]);
+
+impl<'b, H: ?Sized + Haystack, P: AnchoredPattern<H> + Clone> AnchoredPattern<H> for AnyOf<'b, P> {
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize> {
+        self.0.iter().filter_map(|pat| pat.clone().match_prefix(haystack)).max()
+    }
+
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize> {
+        self.0.iter().filter_map(|pat| pat.clone().match_suffix(haystack)).max()
+    }
+}
+
+/// Defines `ExactPattern` implementations for every haystack using `AnchoredSearcher`
+macro_rules! combinator_exact_pattern {
+    ($([$($gen:tt)*] $ty:ty),* $(,)*) => {$(
+        impl<'a, $($gen)*, H: ?Sized + Haystack + 'a> ExactPattern<'a, H> for $ty {
+            type Searcher = AnchoredSearcher<'a, Self, H>;
+
+            fn into_searcher(self, haystack: &'a H) -> Self::Searcher {
+                AnchoredSearcher::new(haystack, self)
+            }
+        }
+    )*};
+}
+
+combinator_exact_pattern! {
+    [P: AnchoredPattern<H>, S: AnchoredPattern<H>] Repeat<P, S>,
+    [A: AnchoredPattern<H>, B: AnchoredPattern<H>] Or<A, B>,
+    [P: AnchoredPattern<H>] Not<P>,
+    [A: AnchoredPattern<H>, B: AnchoredPattern<H>] Then<A, B>,
+    ['b, P: AnchoredPattern<H> + Clone] AnyOf<'b, P>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn repeat_matches_sequence_with_separators() {
+        assert_eq!(Ok("b"),      "aab".trim_left_matches_exactly(Repeat::new("a", 2), 1));
+        assert_eq!(Err("aab"),   "aab".trim_left_matches_exactly(Repeat::new("a", 3), 1));
+        assert_eq!(Ok("aab"),    "aab".trim_left_matches_exactly(Repeat::new("a", 0), 1));
+        assert_eq!(Ok("b"),      "a,ab".trim_left_matches_exactly(Repeat::new("a", 2).separated_by(','), 1));
+        assert_eq!(Err("a,,a"),  "a,,a".trim_left_matches_exactly(Repeat::new("a", 2).separated_by(','), 1));
+        assert_eq!(Ok("b"),      "babab".trim_right_matches_exactly(Repeat::new(('a', 'b'), 2), 1));
+        assert_eq!(Ok("b"),      "ba,a".trim_right_matches_exactly(Repeat::new("a", 2).separated_by(","), 1));
+        assert_eq!(Ok(&b"b"[..]), b"baa"[..].trim_right_matches_exactly(Repeat::new(b'a', 1), 2));
+    }
+
+    #[test]
+    fn or_matches_first_matching_pattern() {
+        assert_eq!(Ok("b"),    "aab".trim_left_matches_exactly(Or("a", "aa"), 2));
+        assert_eq!(Ok(""),     "aab".trim_left_matches_exactly(Or("b", "a"), 3));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(Or("b", "c"), 1));
+        assert_eq!(Ok(""),     "baa".trim_right_matches_exactly(Or("b", "a"), 3));
+    }
+
+    #[test]
+    fn not_matches_single_unit() {
+        assert_eq!(Ok("ab"),   "aab".trim_left_matches_exactly(Not("b"), 1));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(Not("b"), 3));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(Not(""), 1));
+        assert_eq!(Ok("ł"),    "łb".trim_right_matches_exactly(Not("ł"), 1));
+        assert_eq!(Ok("b"),    "łb".trim_left_matches_exactly(Not("b"), 1));
+        assert_eq!(Err(""),    "".trim_left_matches_exactly(Not("b"), 1));
+    }
+
+    #[test]
+    fn then_matches_patterns_in_order() {
+        assert_eq!(Ok("ab"),   "abab".trim_left_matches_exactly(Then("a", 'b'), 1));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(Then("a", 'b'), 1));
+        assert_eq!(Ok("a"),    "aab".trim_right_matches_exactly(Then("a", 'b'), 1));
+    }
+
+    #[test]
+    fn any_of_matches_longest_pattern() {
+        assert_eq!(Ok("b"),    "aab".trim_left_matches_exactly(AnyOf(&["a", "aa"]), 1));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(AnyOf(&["a", "aa"]), 2));
+        assert_eq!(Ok("b"),    "baa".trim_right_matches_exactly(AnyOf(&["aa", "a"]), 1));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(AnyOf::<&str>(&[]), 1));
+    }
+}
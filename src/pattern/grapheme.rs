@@ -0,0 +1,82 @@
+//! Patterns matching extended grapheme clusters
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern};
+
+/// A pattern matching any single extended grapheme cluster, e.g. a letter with its combining marks
+/// or a whole ZWJ emoji sequence.
+///
+/// It's available with the `unicode-segmentation` feature.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::AnyGrapheme;
+/// assert_eq!(Ok("cafe"), "cafe\u{301}".trim_right_matches_exactly(|_: char| true, 1));
+/// assert_eq!(Ok("caf"), "cafe\u{301}".trim_right_matches_exactly(AnyGrapheme, 1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AnyGrapheme;
+
+impl AnchoredPattern for AnyGrapheme {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        haystack.graphemes(true).next().map(str::len)
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        haystack.graphemes(true).next_back().map(str::len)
+    }
+}
+
+/// A pattern matching a single extended grapheme cluster, which the given pattern matches whole.
+///
+/// It's available with the `unicode-segmentation` feature.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Grapheme;
+/// assert_eq!(Ok("caf"), "cafe".trim_right_matches_exactly(Grapheme('e'), 1));
+/// assert_eq!(Err("cafe\u{301}"), "cafe\u{301}".trim_right_matches_exactly(Grapheme('e'), 1));
+/// assert_eq!(Ok("caf"), "cafe\u{301}".trim_right_matches_exactly(Grapheme("e\u{301}"), 1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Grapheme<P>(pub P);
+
+impl<P: AnchoredPattern> AnchoredPattern for Grapheme<P> {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        let grapheme = haystack.graphemes(true).next()?;
+        self.0.match_prefix(grapheme).filter(|&len| len == grapheme.len())
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        let grapheme = haystack.graphemes(true).next_back()?;
+        self.0.match_suffix(grapheme).filter(|&len| len == grapheme.len())
+    }
+}
+
+anchored_exact_pattern! { str;
+    [] AnyGrapheme,
+    [P: AnchoredPattern] Grapheme<P>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn any_grapheme_keeps_clusters_whole() {
+        assert_eq!(Ok("b"),     "a\u{301}ab".trim_left_matches_exactly(AnyGrapheme, 2));
+        assert_eq!(Ok("b"),     "b\u{1F469}\u{200D}\u{1F52C}".trim_right_matches_exactly(AnyGrapheme, 1));
+        assert_eq!(Err("ab"),   "ab".trim_left_matches_exactly(AnyGrapheme, 3));
+    }
+
+    #[test]
+    fn grapheme_matches_whole_clusters() {
+        assert_eq!(Ok("b"),         "aab".trim_left_matches_exactly(Grapheme('a'), 2));
+        assert_eq!(Err("a\u{301}"), "a\u{301}".trim_left_matches_exactly(Grapheme('a'), 1));
+        assert_eq!(Ok("b"),         "ba\u{301}".trim_right_matches_exactly(Grapheme("a\u{301}"), 1));
+    }
+}
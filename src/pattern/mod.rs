@@ -0,0 +1,334 @@
+//! The pattern API used by the trimming methods.
+//!
+//! It mirrors the unstable `core::str::pattern` API, so the crate builds on stable Rust.
+//! It's also generic over the [`Haystack`](trait.Haystack.html), so patterns can be trimmed off
+//! both `str` and `[u8]`.
+//!
+//! Patterns for `str` are implemented for `char`, `&str`, `&String`, `&[char]`, `[char; N]`,
+//! `&[char; N]` and `FnMut(char) -> bool` closures. With the `nightly` feature every
+//! `core::str::pattern::Pattern` is accepted instead.
+//!
+//! Patterns for `[u8]` are implemented for `u8`, byte strings `&[u8]`, `&[u8; N]` and `&Vec<u8>`,
+//! byte sets `[u8; N]` and `FnMut(u8) -> bool` closures.
+//!
+//! With the `std` feature on Unix patterns for `OsStr` are implemented for `char`, `&str`,
+//! `&String`, `&OsStr` and `&OsString`. They are matched against the bytes of the `OsStr`.
+//!
+//! Strings can be matched ignoring case with [`AsciiCaseInsensitive`](struct.AsciiCaseInsensitive.html)
+//! and [`CaseInsensitive`](struct.CaseInsensitive.html).
+//!
+//! Patterns can be combined with [`Repeat`](struct.Repeat.html), which matches a pattern or a tuple
+//! of patterns given number of times in a row, [`Or`](struct.Or.html), [`Not`](struct.Not.html),
+//! [`Then`](struct.Then.html) and [`AnyOf`](struct.AnyOf.html). With the `aho-corasick` feature
+//! many literals can be matched at once with a [`PrefixSet`](struct.PrefixSet.html).
+//! With the `regex-automata` feature regular expressions can be used as a [`Regex`](struct.Regex.html).
+//! With the `unicode-segmentation` feature whole grapheme clusters can be matched
+//! with [`AnyGrapheme`](struct.AnyGrapheme.html) and [`Grapheme`](struct.Grapheme.html).
+
+/// Defines `ExactPattern` implementations using `AnchoredSearcher`
+macro_rules! anchored_exact_pattern {
+    ($haystack:ty; $([$($gen:tt)*] $ty:ty),* $(,)*) => {$(
+        impl<'a, $($gen)*> ExactPattern<'a, $haystack> for $ty {
+            type Searcher = AnchoredSearcher<'a, Self, $haystack>;
+
+            fn into_searcher(self, haystack: &'a $haystack) -> Self::Searcher {
+                AnchoredSearcher::new(haystack, self)
+            }
+        }
+    )*};
+}
+
+mod bytes;
+mod case;
+mod chars;
+mod combinators;
+#[cfg(feature = "unicode-segmentation")]
+mod grapheme;
+#[cfg(feature = "nightly")]
+mod nightly;
+#[cfg(all(feature = "std", unix))]
+mod os_str;
+#[cfg(feature = "aho-corasick")]
+mod prefix_set;
+#[cfg(feature = "regex-automata")]
+mod regex;
+
+pub use self::case::{AsciiCaseInsensitive, CaseInsensitive};
+pub use self::combinators::{AnyOf, Not, Or, Repeat, Then};
+#[cfg(feature = "unicode-segmentation")]
+pub use self::grapheme::{AnyGrapheme, Grapheme};
+#[cfg(feature = "nightly")]
+pub use self::nightly::CoreSearcher;
+#[cfg(feature = "aho-corasick")]
+pub use self::prefix_set::PrefixSet;
+#[cfg(feature = "regex-automata")]
+pub use self::regex::Regex;
+
+/// A sequence which patterns can be trimmed off.
+///
+/// All the indices are byte offsets. A unit is the smallest non-empty sequence which can be
+/// sliced off the haystack, e.g. a `char` for `str` or a byte for `[u8]`.
+///
+/// # Safety
+///
+/// `is_boundary` must return `true` only for indices, at which the haystack can be sliced.
+/// `first_unit_len` and `last_unit_len` must return lengths of units ending or starting at such
+/// indices.
+pub unsafe trait Haystack {
+    /// Returns the length of the haystack
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the haystack is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the haystack can be sliced at the given index
+    fn is_boundary(&self, index: usize) -> bool;
+
+    /// Returns the length of the first unit of the haystack or `None` if it's empty
+    fn first_unit_len(&self) -> Option<usize>;
+
+    /// Returns the length of the last unit of the haystack or `None` if it's empty
+    fn last_unit_len(&self) -> Option<usize>;
+
+    /// Returns a subslice of the haystack without checking the indices.
+    ///
+    /// # Safety
+    ///
+    /// `start <= end <= self.len()` and both the indices must be boundaries.
+    unsafe fn slice_unchecked(&self, start: usize, end: usize) -> &Self;
+}
+
+unsafe impl Haystack for str {
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn is_boundary(&self, index: usize) -> bool {
+        self.is_char_boundary(index)
+    }
+
+    fn first_unit_len(&self) -> Option<usize> {
+        self.chars().next().map(char::len_utf8)
+    }
+
+    fn last_unit_len(&self) -> Option<usize> {
+        self.chars().next_back().map(char::len_utf8)
+    }
+
+    unsafe fn slice_unchecked(&self, start: usize, end: usize) -> &Self {
+        self.get_unchecked(start..end)
+    }
+}
+
+unsafe impl Haystack for [u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn is_boundary(&self, index: usize) -> bool {
+        index <= <[u8]>::len(self)
+    }
+
+    fn first_unit_len(&self) -> Option<usize> {
+        self.first().map(|_| 1)
+    }
+
+    fn last_unit_len(&self) -> Option<usize> {
+        self.last().map(|_| 1)
+    }
+
+    unsafe fn slice_unchecked(&self, start: usize, end: usize) -> &Self {
+        self.get_unchecked(start..end)
+    }
+}
+
+/// Result of a single step of a [`Searcher`](trait.Searcher.html)
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SearchStep {
+    /// The pattern matched `haystack[a..b]`
+    Match(usize, usize),
+    /// `haystack[a..b]` can't contain a match of the pattern
+    Reject(usize, usize),
+    /// Every byte of the haystack has been visited
+    Done,
+}
+
+/// A pattern which can be trimmed off a haystack, by default a `&str`
+pub trait ExactPattern<'a, H: ?Sized + Haystack = str>: Sized {
+    /// The searcher used for this pattern
+    type Searcher: Searcher<'a, H>;
+
+    /// Creates a searcher for this pattern in the given haystack
+    fn into_searcher(self, haystack: &'a H) -> Self::Searcher;
+}
+
+/// A searcher stepping through a haystack from its beginning.
+///
+/// # Safety
+///
+/// Steps returned by `next` must be contiguous, non-overlapping and cover the whole haystack.
+/// Their indices must be boundaries of the haystack.
+pub unsafe trait Searcher<'a, H: ?Sized + Haystack = str> {
+    /// Returns the haystack being searched
+    fn haystack(&self) -> &'a H;
+
+    /// Performs the next search step starting from the front
+    fn next(&mut self) -> SearchStep;
+}
+
+/// A searcher stepping through a haystack from its end.
+///
+/// # Safety
+///
+/// Steps returned by `next_back` must be contiguous, non-overlapping and cover the whole haystack.
+/// Their indices must be boundaries of the haystack.
+pub unsafe trait ReverseSearcher<'a, H: ?Sized + Haystack = str>: Searcher<'a, H> {
+    /// Performs the next search step starting from the back
+    fn next_back(&mut self) -> SearchStep;
+}
+
+/// A pattern which can be matched at the very beginning or the very end of a haystack.
+///
+/// Implementing it and using [`AnchoredSearcher`](struct.AnchoredSearcher.html)
+/// is the simplest way of writing an [`ExactPattern`](trait.ExactPattern.html).
+pub trait AnchoredPattern<H: ?Sized + Haystack = str> {
+    /// Returns the length of the match at the beginning of the haystack
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize>;
+
+    /// Returns the length of the match at the end of the haystack
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize>;
+}
+
+/// A searcher for an [`AnchoredPattern`](trait.AnchoredPattern.html).
+///
+/// Just like the searchers from `core`, it never returns two empty matches at the same position
+/// in a row, so an empty pattern can't be trimmed off more than once.
+#[derive(Clone, Debug)]
+pub struct AnchoredSearcher<'a, P, H: ?Sized + 'a = str> {
+    haystack: &'a H,
+    pattern: P,
+    front: usize,
+    back: usize,
+    front_empty_match: bool,
+    back_empty_match: bool,
+}
+
+impl<'a, P: AnchoredPattern<H>, H: ?Sized + Haystack> AnchoredSearcher<'a, P, H> {
+    /// Creates a searcher for the pattern in the given haystack
+    pub fn new(haystack: &'a H, pattern: P) -> Self {
+        AnchoredSearcher {
+            haystack,
+            pattern,
+            front: 0,
+            back: haystack.len(),
+            front_empty_match: false,
+            back_empty_match: false,
+        }
+    }
+
+    fn remaining(&self) -> &'a H {
+        unsafe { self.haystack.slice_unchecked(self.front, self.back) }
+    }
+}
+
+unsafe impl<'a, P: AnchoredPattern<H>, H: ?Sized + Haystack> Searcher<'a, H> for AnchoredSearcher<'a, P, H> {
+    fn haystack(&self) -> &'a H {
+        self.haystack
+    }
+
+    fn next(&mut self) -> SearchStep {
+        let remaining = self.remaining();
+        let start = self.front;
+        match self.pattern.match_prefix(remaining) {
+            Some(len) if len != 0 || !self.front_empty_match => {
+                assert!(remaining.is_boundary(len), "Pattern matched outside of a boundary");
+                self.front += len;
+                self.front_empty_match = len == 0;
+                SearchStep::Match(start, self.front)
+            }
+            _ => {
+                self.front_empty_match = false;
+                match remaining.first_unit_len() {
+                    Some(len) => {
+                        self.front += len;
+                        SearchStep::Reject(start, self.front)
+                    }
+                    None => SearchStep::Done,
+                }
+            }
+        }
+    }
+}
+
+unsafe impl<'a, P: AnchoredPattern<H>, H: ?Sized + Haystack> ReverseSearcher<'a, H>
+        for AnchoredSearcher<'a, P, H> {
+    fn next_back(&mut self) -> SearchStep {
+        let remaining = self.remaining();
+        let end = self.back;
+        match self.pattern.match_suffix(remaining) {
+            Some(len) if len != 0 || !self.back_empty_match => {
+                assert!(len <= remaining.len() && remaining.is_boundary(remaining.len() - len),
+                    "Pattern matched outside of a boundary");
+                self.back -= len;
+                self.back_empty_match = len == 0;
+                SearchStep::Match(self.back, end)
+            }
+            _ => {
+                self.back_empty_match = false;
+                match remaining.last_unit_len() {
+                    Some(len) => {
+                        self.back -= len;
+                        SearchStep::Reject(self.back, end)
+                    }
+                    None => SearchStep::Done,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps_forward<'a, H, P>(haystack: &'a H, pat: P) -> [SearchStep; 4]
+            where H: ?Sized + Haystack, P: ExactPattern<'a, H> {
+        let mut searcher = pat.into_searcher(haystack);
+        [searcher.next(), searcher.next(), searcher.next(), searcher.next()]
+    }
+
+    fn steps_backward<'a, H, P>(haystack: &'a H, pat: P) -> [SearchStep; 4]
+            where H: ?Sized + Haystack, P: ExactPattern<'a, H>, P::Searcher: ReverseSearcher<'a, H> {
+        let mut searcher = pat.into_searcher(haystack);
+        [searcher.next_back(), searcher.next_back(), searcher.next_back(), searcher.next_back()]
+    }
+
+    #[test]
+    fn searcher_steps_through_str() {
+        use self::SearchStep::*;
+        assert_eq!([Match(0, 1), Match(1, 2), Reject(2, 4), Done], steps_forward("aaż", 'a'));
+        assert_eq!([Match(0, 0), Reject(0, 1), Match(1, 1), Reject(1, 2)], steps_forward("ab", ""));
+        assert_eq!([Match(0, 2), Reject(2, 3), Done, Done], steps_forward("abc", "ab"));
+        assert_eq!([Match(0, 1), Match(1, 3), Reject(3, 4), Done], steps_forward("aźb", ['a', 'ź']));
+        assert_eq!([Reject(2, 4), Match(1, 2), Match(0, 1), Done], steps_backward("aaż", 'a'));
+        assert_eq!([Match(2, 2), Reject(1, 2), Match(1, 1), Reject(0, 1)], steps_backward("ab", ""));
+        assert_eq!([Match(1, 3), Reject(0, 1), Done, Done], steps_backward("abc", "bc"));
+        assert_eq!([Match(3, 4), Match(1, 3), Reject(0, 1), Done],
+            steps_backward("aźb", |c: char| c != 'a'));
+    }
+
+    #[test]
+    fn searcher_steps_through_bytes() {
+        use self::SearchStep::*;
+        let haystack: &[u8] = b"aabc";
+        assert_eq!([Match(0, 1), Match(1, 2), Reject(2, 3), Reject(3, 4)], steps_forward(haystack, b'a'));
+        assert_eq!([Match(0, 2), Reject(2, 3), Reject(3, 4), Done], steps_forward(haystack, b"aa"));
+        assert_eq!([Match(0, 0), Reject(0, 1), Match(1, 1), Reject(1, 2)], steps_forward(haystack, b""));
+        assert_eq!([Match(3, 4), Match(2, 3), Reject(1, 2), Reject(0, 1)],
+            steps_backward(haystack, *b"bc"));
+        assert_eq!([Match(3, 4), Reject(2, 3), Reject(1, 2), Reject(0, 1)],
+            steps_backward(haystack, |b| b == b'c'));
+    }
+}
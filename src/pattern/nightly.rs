@@ -0,0 +1,41 @@
+//! Patterns for `str` forwarded to `core::str::pattern`
+
+use core::str::pattern;
+
+use super::{ExactPattern, ReverseSearcher, Searcher, SearchStep};
+
+/// A [`Searcher`](../trait.Searcher.html) forwarding to a searcher from `core::str::pattern`
+#[derive(Clone, Debug)]
+pub struct CoreSearcher<S>(S);
+
+impl<'a, P: pattern::Pattern> ExactPattern<'a> for P {
+    type Searcher = CoreSearcher<P::Searcher<'a>>;
+
+    fn into_searcher(self, haystack: &'a str) -> Self::Searcher {
+        CoreSearcher(pattern::Pattern::into_searcher(self, haystack))
+    }
+}
+
+fn search_step(step: pattern::SearchStep) -> SearchStep {
+    match step {
+        pattern::SearchStep::Match(a, b) => SearchStep::Match(a, b),
+        pattern::SearchStep::Reject(a, b) => SearchStep::Reject(a, b),
+        pattern::SearchStep::Done => SearchStep::Done,
+    }
+}
+
+unsafe impl<'a, S: pattern::Searcher<'a>> Searcher<'a> for CoreSearcher<S> {
+    fn haystack(&self) -> &'a str {
+        self.0.haystack()
+    }
+
+    fn next(&mut self) -> SearchStep {
+        search_step(self.0.next())
+    }
+}
+
+unsafe impl<'a, S: pattern::ReverseSearcher<'a>> ReverseSearcher<'a> for CoreSearcher<S> {
+    fn next_back(&mut self) -> SearchStep {
+        search_step(self.0.next_back())
+    }
+}
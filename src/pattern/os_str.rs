@@ -0,0 +1,111 @@
+//! Patterns for `OsStr` on Unix, matched against its bytes
+
+use std::ffi::{OsStr, OsString};
+use std::os::unix::ffi::OsStrExt;
+use std::string::String;
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern, Haystack};
+
+unsafe impl Haystack for OsStr {
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    fn is_boundary(&self, index: usize) -> bool {
+        self.as_bytes().is_boundary(index)
+    }
+
+    fn first_unit_len(&self) -> Option<usize> {
+        self.as_bytes().first_unit_len()
+    }
+
+    fn last_unit_len(&self) -> Option<usize> {
+        self.as_bytes().last_unit_len()
+    }
+
+    unsafe fn slice_unchecked(&self, start: usize, end: usize) -> &Self {
+        OsStr::from_bytes(self.as_bytes().slice_unchecked(start, end))
+    }
+}
+
+impl AnchoredPattern<OsStr> for char {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.encode_utf8(&mut [0; 4]).as_bytes().match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.encode_utf8(&mut [0; 4]).as_bytes().match_suffix(haystack.as_bytes())
+    }
+}
+
+impl AnchoredPattern<OsStr> for &str {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_suffix(haystack.as_bytes())
+    }
+}
+
+impl AnchoredPattern<OsStr> for &String {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_suffix(haystack.as_bytes())
+    }
+}
+
+impl AnchoredPattern<OsStr> for &OsStr {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_suffix(haystack.as_bytes())
+    }
+}
+
+impl AnchoredPattern<OsStr> for &OsString {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.as_bytes().match_suffix(haystack.as_bytes())
+    }
+}
+
+anchored_exact_pattern! { OsStr;
+    [] char,
+    ['b] &'b str,
+    ['b] &'b String,
+    ['b] &'b OsStr,
+    ['b] &'b OsString,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn trims_os_str() {
+        let arg = OsStr::new("--file.tar.gz");
+
+        assert_eq!(Ok(OsStr::new("file.tar.gz")), arg.trim_left_matches_exactly('-', 2));
+        assert_eq!(Err(arg), arg.trim_left_matches_exactly("-", 3));
+        assert_eq!(Ok(OsStr::new("--file")), arg.trim_right_matches_exactly(OsStr::new(".tar.gz"), 1));
+        assert_eq!(Ok(OsStr::new("--file.tar")), arg.trim_right_matches_exactly(".gz", 1));
+    }
+
+    #[test]
+    fn trims_non_utf8_os_str() {
+        let arg = OsStr::from_bytes(b"\xff\xfe--");
+
+        assert_eq!(Ok(OsStr::from_bytes(b"\xff\xfe")), arg.trim_right_matches_exactly('-', 2));
+        assert_eq!(Err(arg), arg.trim_left_matches_exactly('-', 1));
+    }
+}
@@ -0,0 +1,185 @@
+//! Multi-literal pattern backed by the Aho-Corasick automaton
+
+use aho_corasick::{AhoCorasick, Anchored, BuildError, Input, MatchKind, StartKind};
+use alloc::vec::Vec;
+#[cfg(all(feature = "std", unix))]
+use std::ffi::OsStr;
+#[cfg(all(feature = "std", unix))]
+use std::os::unix::ffi::OsStrExt;
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern};
+
+/// A prebuilt set of literals, which matches the longest of them.
+///
+/// It's much faster than [`AnyOf`](struct.AnyOf.html) for many literals.
+/// Matching at the end of a haystack uses a second automaton built from the reversed literals,
+/// which is fed with a reversed copy of the haystack's tail. The copy is kept on the stack,
+/// unless the longest literal exceeds 64 bytes.
+/// When trimming `str`, literals not ending at a `char` boundary are ignored,
+/// so the longest literal, which does, is matched.
+///
+/// It's available with the `aho-corasick` feature.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::PrefixSet;
+/// let comments = PrefixSet::new(["#", "//", "///", "--"]).unwrap();
+/// assert_eq!(Ok(" doc"), "/// doc".trim_left_matches_exactly(&comments, 1));
+/// assert_eq!(Ok(" note"), "#// note".trim_left_matches_exactly(&comments, 2));
+/// assert_eq!(Err("text"), "text".trim_left_matches_exactly(&comments, 1));
+/// assert_eq!(Ok("text "), "text --".trim_right_matches_exactly(&comments, 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrefixSet {
+    forward: AhoCorasick,
+    reverse: AhoCorasick,
+}
+
+impl PrefixSet {
+    /// Builds the set from the given literals
+    pub fn new<I, P>(literals: I) -> Result<Self, BuildError>
+            where I: IntoIterator<Item = P>, P: AsRef<[u8]> {
+        let literals: Vec<P> = literals.into_iter().collect();
+        let reversed_literals = literals.iter()
+            .map(|literal| literal.as_ref().iter().rev().cloned().collect::<Vec<u8>>());
+        Ok(PrefixSet {
+            forward: build_automaton(&literals)?,
+            reverse: build_automaton(reversed_literals)?,
+        })
+    }
+}
+
+fn build_automaton<I, P>(literals: I) -> Result<AhoCorasick, BuildError>
+        where I: IntoIterator<Item = P>, P: AsRef<[u8]> {
+    AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .start_kind(StartKind::Anchored)
+        .build(literals)
+}
+
+/// The longest haystack tail, which is reversed on the stack
+const STACK_BUFFER_LEN: usize = 64;
+
+impl PrefixSet {
+    /// Finds the longest literal at the beginning of the haystack with an accepted length
+    fn longest_prefix<F: FnMut(usize) -> bool>(&self, haystack: &[u8], accept: F) -> Option<usize> {
+        longest_match(&self.forward, haystack, accept)
+    }
+
+    /// Finds the longest literal at the end of the haystack with an accepted length
+    fn longest_suffix<F: FnMut(usize) -> bool>(&self, haystack: &[u8], accept: F) -> Option<usize> {
+        let tail = &haystack[haystack.len().saturating_sub(self.reverse.max_pattern_len())..];
+        let mut stack_buffer = [0; STACK_BUFFER_LEN];
+        let mut heap_buffer = Vec::new();
+        let reversed_tail = if tail.len() <= STACK_BUFFER_LEN {
+            &mut stack_buffer[..tail.len()]
+        } else {
+            heap_buffer.resize(tail.len(), 0);
+            &mut heap_buffer[..]
+        };
+        for (reversed, &byte) in reversed_tail.iter_mut().zip(tail.iter().rev()) {
+            *reversed = byte;
+        }
+        longest_match(&self.reverse, reversed_tail, accept)
+    }
+}
+
+/// Finds the longest accepted match by searching again before the end of each rejected one
+fn longest_match<F: FnMut(usize) -> bool>(automaton: &AhoCorasick, haystack: &[u8], mut accept: F)
+        -> Option<usize> {
+    let mut search_end = haystack.len();
+    loop {
+        let len = automaton.find(Input::new(&haystack[..search_end]).anchored(Anchored::Yes))?.end();
+        if accept(len) {
+            return Some(len);
+        }
+        search_end = len.checked_sub(1)?;
+    }
+}
+
+impl AnchoredPattern<[u8]> for &PrefixSet {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.longest_prefix(haystack, |_| true)
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.longest_suffix(haystack, |_| true)
+    }
+}
+
+impl AnchoredPattern for &PrefixSet {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        self.longest_prefix(haystack.as_bytes(), |len| haystack.is_char_boundary(len))
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        self.longest_suffix(haystack.as_bytes(), |len| haystack.is_char_boundary(haystack.len() - len))
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl AnchoredPattern<OsStr> for &PrefixSet {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.match_suffix(haystack.as_bytes())
+    }
+}
+
+anchored_exact_pattern! { str;
+    ['b] &'b PrefixSet,
+}
+
+anchored_exact_pattern! { [u8];
+    ['b] &'b PrefixSet,
+}
+
+#[cfg(all(feature = "std", unix))]
+anchored_exact_pattern! { OsStr;
+    ['b] &'b PrefixSet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn matches_longest_literal() {
+        let set = PrefixSet::new(["a", "aa", "ba"]).unwrap();
+
+        assert_eq!(Ok("b"),    "aab".trim_left_matches_exactly(&set, 1));
+        assert_eq!(Err("aab"), "aab".trim_left_matches_exactly(&set, 2));
+        assert_eq!(Ok(""),     "baaa".trim_right_matches_exactly(&set, 2));
+        assert_eq!(Ok("b"),    "baa".trim_right_matches_exactly(&set, 1));
+        assert_eq!(Ok(&b"b"[..]), b"aab"[..].trim_left_matches_exactly(&set, 1));
+    }
+
+    #[test]
+    fn ignores_matches_inside_char() {
+        let set = PrefixSet::new([&b"\xC5"[..], b"\x82"]).unwrap();
+
+        assert_eq!(Err("ł"), "ł".trim_left_matches_exactly(&set, 1));
+        assert_eq!(Err("ł"), "ł".trim_right_matches_exactly(&set, 1));
+        assert_eq!(Ok(&b""[..]), "ł".as_bytes().trim_left_matches_exactly(&set, 2));
+
+        let set = PrefixSet::new([&b"a"[..], b"a\xC5", b"\x82a", b"a"]).unwrap();
+
+        assert_eq!(Ok("ł"), "ał".trim_left_matches_exactly(&set, 1));
+        assert_eq!(Ok("ł"), "ła".trim_right_matches_exactly(&set, 1));
+        assert_eq!(Ok(&b"\x82"[..]), "ał".as_bytes().trim_left_matches_exactly(&set, 1));
+    }
+
+    #[test]
+    fn matches_literals_longer_than_stack_buffer() {
+        let long = "ab".repeat(STACK_BUFFER_LEN);
+        let set = PrefixSet::new([&long[..], "b"]).unwrap();
+        let haystack = ["a", &long, &long].concat();
+
+        assert_eq!(Ok("a"),  haystack.trim_right_matches_exactly(&set, 2));
+        assert_eq!(Ok("ba"), "bab".trim_right_matches_exactly(&set, 1));
+    }
+}
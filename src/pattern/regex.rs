@@ -0,0 +1,144 @@
+//! Regex pattern backed by the DFAs of `regex-automata`
+
+use alloc::vec::Vec;
+use regex_automata::dfa::dense::{self, BuildError, DFA};
+use regex_automata::dfa::{Automaton, StartKind};
+use regex_automata::nfa::thompson;
+use regex_automata::{Anchored, Input, MatchKind};
+#[cfg(all(feature = "std", unix))]
+use std::ffi::OsStr;
+#[cfg(all(feature = "std", unix))]
+use std::os::unix::ffi::OsStrExt;
+
+use super::{AnchoredPattern, AnchoredSearcher, ExactPattern};
+
+/// A prebuilt regular expression, which matches the longest prefix or suffix it can.
+///
+/// It's compiled into two anchored DFAs, a forward one for prefixes and a reverse one for suffixes.
+/// The syntax is the one of the `regex` crate. The regex matches only valid UTF-8.
+/// Building each DFA is limited to 2 MiB of memory, so regexes with too many states fail to compile.
+///
+/// It's available with the `regex-automata` feature.
+///
+/// # Examples
+/// ```
+/// # use trim_matches_exactly::TrimMatchesExactlyExt;
+/// # use trim_matches_exactly::pattern::Regex;
+/// let tag = Regex::new("[A-Z]+: ").unwrap();
+/// assert_eq!(Ok("disk full"), "WARN: ERROR: disk full".trim_left_matches_exactly(&tag, 2));
+/// assert_eq!(Err("WARN: disk full"), "WARN: disk full".trim_left_matches_exactly(&tag, 2));
+///
+/// let timestamp = Regex::new(r" \d{2}:\d{2}").unwrap();
+/// assert_eq!(Ok("done"), "done 12:00 12:05".trim_right_matches_exactly(&timestamp, 2));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Regex {
+    forward: DFA<Vec<u32>>,
+    reverse: DFA<Vec<u32>>,
+}
+
+/// The memory limit of building a DFA, which stops patterns with exponentially many states
+const SIZE_LIMIT: usize = 2 << 20;
+
+impl Regex {
+    /// Compiles the regular expression.
+    /// Fails if the syntax is invalid or a DFA exceeds the memory limit.
+    #[allow(clippy::result_large_err)]
+    pub fn new(pattern: &str) -> Result<Self, BuildError> {
+        let config = dense::Config::new()
+            .match_kind(MatchKind::All)
+            .start_kind(StartKind::Anchored)
+            .dfa_size_limit(Some(SIZE_LIMIT))
+            .determinize_size_limit(Some(SIZE_LIMIT));
+        Ok(Regex {
+            forward: dense::Builder::new()
+                .configure(config.clone())
+                .build(pattern)?,
+            reverse: dense::Builder::new()
+                .configure(config)
+                .thompson(thompson::Config::new().reverse(true))
+                .build(pattern)?,
+        })
+    }
+}
+
+impl AnchoredPattern<[u8]> for &Regex {
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.forward.try_search_fwd(&Input::new(haystack).anchored(Anchored::Yes))
+            .expect("DFA without quit bytes failed")
+            .map(|found| found.offset())
+    }
+
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.reverse.try_search_rev(&Input::new(haystack).anchored(Anchored::Yes))
+            .expect("DFA without quit bytes failed")
+            .map(|found| haystack.len() - found.offset())
+    }
+}
+
+impl AnchoredPattern for &Regex {
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        self.match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        self.match_suffix(haystack.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl AnchoredPattern<OsStr> for &Regex {
+    fn match_prefix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.match_prefix(haystack.as_bytes())
+    }
+
+    fn match_suffix(&mut self, haystack: &OsStr) -> Option<usize> {
+        self.match_suffix(haystack.as_bytes())
+    }
+}
+
+anchored_exact_pattern! { str;
+    ['b] &'b Regex,
+}
+
+anchored_exact_pattern! { [u8];
+    ['b] &'b Regex,
+}
+
+#[cfg(all(feature = "std", unix))]
+anchored_exact_pattern! { OsStr;
+    ['b] &'b Regex,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TrimMatchesExactlyExt;
+
+    #[test]
+    fn matches_longest_prefix_and_suffix() {
+        let regex = Regex::new("a|ab|ba").unwrap();
+
+        assert_eq!(Ok(""),      "abab".trim_left_matches_exactly(&regex, 2));
+        assert_eq!(Err("abab"), "abab".trim_left_matches_exactly(&regex, 3));
+        assert_eq!(Ok("ab"),    "abab".trim_right_matches_exactly(&regex, 1));
+        assert_eq!(Ok(""),      "aba".trim_right_matches_exactly(&regex, 2));
+        assert_eq!(Err("b"),    "b".trim_right_matches_exactly(&regex, 1));
+        assert_eq!(Ok(&b""[..]), b"aab"[..].trim_left_matches_exactly(&regex, 2));
+    }
+
+    #[test]
+    fn matches_whole_chars() {
+        let regex = Regex::new(".").unwrap();
+
+        assert_eq!(Ok("ł"), "łł".trim_left_matches_exactly(&regex, 1));
+        assert_eq!(Ok("ł"), "łł".trim_right_matches_exactly(&regex, 1));
+        assert_eq!(Err(&b"\xFF"[..]), b"\xFF"[..].trim_left_matches_exactly(&regex, 1));
+    }
+
+    #[test]
+    fn rejects_exponentially_large_dfa() {
+        assert!(Regex::new("[ab]*a[ab]{12}").is_ok());
+        assert!(Regex::new("[ab]*a[ab]{30}").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use core::ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive};
+
+/// The number of times a pattern is allowed to be trimmed off.
+///
+/// It can be created from a `usize` or from an inclusive or unbounded range:
+///
+/// ```
+/// # use trim_matches_exactly::Quantifier;
+/// assert_eq!(Quantifier::Exactly(2), 2.into());
+/// assert_eq!(Quantifier::Between(2, 4), (2..=4).into());
+/// assert_eq!(Quantifier::AtLeast(3), (3..).into());
+/// assert_eq!(Quantifier::AtMost(1), (..=1).into());
+/// assert_eq!(Quantifier::AtLeast(0), (..).into());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Quantifier {
+    /// Exactly the given number of times
+    Exactly(usize),
+    /// The given number of times or more
+    AtLeast(usize),
+    /// The given number of times or less
+    AtMost(usize),
+    /// Between the given numbers of times, both inclusive
+    Between(usize, usize),
+}
+
+impl Quantifier {
+    /// Returns the smallest accepted number of matches
+    pub fn min(self) -> usize {
+        match self {
+            Quantifier::Exactly(count) | Quantifier::AtLeast(count) | Quantifier::Between(count, _) => count,
+            Quantifier::AtMost(_) => 0,
+        }
+    }
+
+    /// Returns the largest accepted number of matches or `None` if it's unbounded
+    pub fn max(self) -> Option<usize> {
+        match self {
+            Quantifier::Exactly(count) | Quantifier::AtMost(count) | Quantifier::Between(_, count) => Some(count),
+            Quantifier::AtLeast(_) => None,
+        }
+    }
+
+    /// Returns `true` if the given number of matches is accepted
+    pub fn contains(self, count: usize) -> bool {
+        self.min() <= count && self.max().is_none_or(|max| count <= max)
+    }
+}
+
+impl From<usize> for Quantifier {
+    fn from(count: usize) -> Self {
+        Quantifier::Exactly(count)
+    }
+}
+
+impl From<RangeInclusive<usize>> for Quantifier {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Quantifier::Between(*range.start(), *range.end())
+    }
+}
+
+impl From<RangeFrom<usize>> for Quantifier {
+    fn from(range: RangeFrom<usize>) -> Self {
+        Quantifier::AtLeast(range.start)
+    }
+}
+
+impl From<RangeToInclusive<usize>> for Quantifier {
+    fn from(range: RangeToInclusive<usize>) -> Self {
+        Quantifier::AtMost(range.end)
+    }
+}
+
+impl From<RangeFull> for Quantifier {
+    fn from(_: RangeFull) -> Self {
+        Quantifier::AtLeast(0)
+    }
+}
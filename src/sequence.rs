@@ -0,0 +1,158 @@
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+use pattern::{ExactPattern, Haystack, ReverseSearcher};
+use {left_trim_idx, right_trim_idx, TrimError};
+
+/// A sequence of `(pattern, count)` steps, which can be trimmed off the beginning of a haystack.
+///
+/// It's implemented for tuples of up to 8 steps with different pattern types
+/// and for arrays of steps with the same pattern type.
+pub trait Sequence<'a, H: ?Sized + Haystack = str> {
+    /// Trims off all the steps in order, returns the byte offset, at which the last step ends
+    fn trim_left_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>>;
+}
+
+/// A sequence of `(pattern, count)` steps, which can be trimmed off the end of a haystack.
+///
+/// It's implemented for tuples of up to 8 steps with different pattern types
+/// and for arrays of steps with the same pattern type.
+pub trait ReverseSequence<'a, H: ?Sized + Haystack = str> {
+    /// Trims off all the steps in order, returns the byte offset, at which the last step starts
+    fn trim_right_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>>;
+}
+
+/// The error returned when a sequence of patterns can't be trimmed off
+pub struct SequenceError<'a, H: ?Sized + 'a = str> {
+    step: usize,
+    error: TrimError<'a, H>,
+}
+
+impl<'a, H: ?Sized> SequenceError<'a, H> {
+    /// Returns the untrimmed haystack
+    pub fn haystack(&self) -> &'a H {
+        self.error.haystack()
+    }
+
+    /// Returns the index of the step, which failed
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the error of the step, which failed. Its position is a byte offset in the untrimmed haystack.
+    pub fn error(&self) -> TrimError<'a, H> {
+        self.error
+    }
+}
+
+impl<'a, H: ?Sized> Clone for SequenceError<'a, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, H: ?Sized> Copy for SequenceError<'a, H> {}
+
+impl<'a, H: ?Sized + PartialEq> PartialEq for SequenceError<'a, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.step == other.step && self.error == other.error
+    }
+}
+
+impl<'a, H: ?Sized + Eq> Eq for SequenceError<'a, H> {}
+
+impl<'a, H: ?Sized + Debug> Debug for SequenceError<'a, H> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SequenceError")
+            .field("step", &self.step)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<'a, H: ?Sized> Display for SequenceError<'a, H> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "step {} failed: {}", self.step, self.error)
+    }
+}
+
+impl<'a, H: ?Sized + Debug> Error for SequenceError<'a, H> {}
+
+fn step_left<'a, H, P>(haystack: &'a H, start: usize, step: usize, (pat, count): (P, usize))
+        -> Result<usize, SequenceError<'a, H>>
+        where H: ?Sized + Haystack, P: ExactPattern<'a, H> {
+    let rest = unsafe { haystack.slice_unchecked(start, haystack.len()) };
+    match left_trim_idx(&mut pat.into_searcher(rest), count) {
+        Ok(trim_idx) => Ok(start + trim_idx),
+        Err(error) => Err(SequenceError {
+            step,
+            error: TrimError::new(haystack, error.matches(), start + error.position(), error.kind()),
+        }),
+    }
+}
+
+fn step_right<'a, H, P>(haystack: &'a H, end: usize, step: usize, (pat, count): (P, usize))
+        -> Result<usize, SequenceError<'a, H>>
+        where H: ?Sized + Haystack, P: ExactPattern<'a, H>, P::Searcher: ReverseSearcher<'a, H> {
+    let rest = unsafe { haystack.slice_unchecked(0, end) };
+    match right_trim_idx(&mut pat.into_searcher(rest), count) {
+        Ok(trim_idx) => Ok(trim_idx),
+        Err(error) => Err(SequenceError {
+            step,
+            error: TrimError::new(haystack, error.matches(), error.position(), error.kind()),
+        }),
+    }
+}
+
+macro_rules! tuple_sequence {
+    ($($idx:tt $pat:ident),+) => {
+        impl<'a, H: ?Sized + Haystack, $($pat: ExactPattern<'a, H>),+> Sequence<'a, H>
+                for ($(($pat, usize),)+) {
+            fn trim_left_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>> {
+                let trim_idx = 0;
+                $(let trim_idx = step_left(haystack, trim_idx, $idx, self.$idx)?;)+
+                Ok(trim_idx)
+            }
+        }
+
+        impl<'a, H: ?Sized + Haystack, $($pat: ExactPattern<'a, H>),+> ReverseSequence<'a, H>
+                for ($(($pat, usize),)+)
+                where $($pat::Searcher: ReverseSearcher<'a, H>),+ {
+            fn trim_right_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>> {
+                let trim_idx = haystack.len();
+                $(let trim_idx = step_right(haystack, trim_idx, $idx, self.$idx)?;)+
+                Ok(trim_idx)
+            }
+        }
+    };
+}
+
+tuple_sequence!(0 A);
+tuple_sequence!(0 A, 1 B);
+tuple_sequence!(0 A, 1 B, 2 C);
+tuple_sequence!(0 A, 1 B, 2 C, 3 D);
+tuple_sequence!(0 A, 1 B, 2 C, 3 D, 4 E);
+tuple_sequence!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+tuple_sequence!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+tuple_sequence!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 I);
+
+impl<'a, H: ?Sized + Haystack, P: ExactPattern<'a, H>, const N: usize> Sequence<'a, H> for [(P, usize); N] {
+    fn trim_left_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>> {
+        let mut trim_idx = 0;
+        for (step, pat_count) in IntoIterator::into_iter(self).enumerate() {
+            trim_idx = step_left(haystack, trim_idx, step, pat_count)?;
+        }
+        Ok(trim_idx)
+    }
+}
+
+impl<'a, H: ?Sized + Haystack, P: ExactPattern<'a, H>, const N: usize> ReverseSequence<'a, H> for [(P, usize); N]
+        where P::Searcher: ReverseSearcher<'a, H> {
+    fn trim_right_idx(self, haystack: &'a H) -> Result<usize, SequenceError<'a, H>> {
+        let mut trim_idx = haystack.len();
+        for (step, pat_count) in IntoIterator::into_iter(self).enumerate() {
+            trim_idx = step_right(haystack, trim_idx, step, pat_count)?;
+        }
+        Ok(trim_idx)
+    }
+}
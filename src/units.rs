@@ -0,0 +1,131 @@
+#[cfg(feature = "unicode-segmentation")]
+use TrimGraphemesExactlyExt;
+use TrimMatchesExactlyExt;
+
+/// The unit of `str`, which can be trimmed regardless of its content.
+/// It's non-exhaustive, because the `unicode-segmentation` feature adds a variant.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Unit {
+    /// A single `char`
+    Char,
+    /// A single byte. Trimming fails if it would split a `char`.
+    Byte,
+    /// An extended grapheme cluster, available with the `unicode-segmentation` feature
+    #[cfg(feature = "unicode-segmentation")]
+    Grapheme,
+}
+
+/// The extension trait adding methods for trimming given number of units off `str`
+pub trait TrimUnitsExactlyExt {
+    /// Returns '&str' with given number of units trimmed from its beginning.
+    /// If it has fewer units or trimming bytes would split a `char`,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{TrimUnitsExactlyExt, Unit};
+    /// assert_eq!(Ok("żółw"), "20240101żółw".trim_left_units_exactly(Unit::Char, 8));
+    /// assert_eq!(Ok("łw"), "żółw".trim_left_units_exactly(Unit::Byte, 4));
+    /// assert_eq!(Err("żółw"), "żółw".trim_left_units_exactly(Unit::Byte, 3));
+    /// assert_eq!(Err("żółw"), "żółw".trim_left_units_exactly(Unit::Char, 5));
+    /// ```
+    fn trim_left_units_exactly(&self, unit: Unit, count: usize) -> Result<&str, &str>;
+
+    /// Returns '&str' with given number of units trimmed from its end.
+    /// If it has fewer units or trimming bytes would split a `char`,
+    /// returns `Err` with an untrimmed `&str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use trim_matches_exactly::{TrimUnitsExactlyExt, Unit};
+    /// assert_eq!(Ok("żó"), "żółw".trim_right_units_exactly(Unit::Char, 2));
+    /// assert_eq!(Ok("żó"), "żółw".trim_right_units_exactly(Unit::Byte, 3));
+    /// assert_eq!(Err("żółw"), "żółw".trim_right_units_exactly(Unit::Byte, 2));
+    /// ```
+    fn trim_right_units_exactly(&self, unit: Unit, count: usize) -> Result<&str, &str>;
+}
+
+impl TrimUnitsExactlyExt for str {
+    fn trim_left_units_exactly(&self, unit: Unit, count: usize) -> Result<&str, &str> {
+        match unit {
+            Unit::Char => self.trim_left_matches_exactly(|_: char| true, count),
+            Unit::Byte => match self.get(count..) {
+                Some(trimmed) => Ok(trimmed),
+                None => Err(self),
+            },
+            #[cfg(feature = "unicode-segmentation")]
+            Unit::Grapheme => self.trim_left_graphemes_exactly(count),
+        }
+    }
+
+    fn trim_right_units_exactly(&self, unit: Unit, count: usize) -> Result<&str, &str> {
+        match unit {
+            Unit::Char => self.trim_right_matches_exactly(|_: char| true, count),
+            Unit::Byte => match self.len().checked_sub(count).and_then(|trim_idx| self.get(..trim_idx)) {
+                Some(trimmed) => Ok(trimmed),
+                None => Err(self),
+            },
+            #[cfg(feature = "unicode-segmentation")]
+            Unit::Grapheme => self.trim_right_graphemes_exactly(count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn trims_graphemes() {
+        assert_eq!(Ok("b"), "a\u{301}b".trim_left_units_exactly(Unit::Grapheme, 1));
+        assert_eq!(Ok("b"), "ba\u{301}".trim_right_units_exactly(Unit::Grapheme, 1));
+    }
+
+    mod trim_left_units_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, unit: Unit, count: usize) {
+            let actual = haystack.trim_left_units_exactly(unit, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', unit '{:?}' and count '{}'", haystack, unit, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("łb"),   "ałb", Unit::Char, 1);
+            assert_trim(Ok("b"),    "ałb", Unit::Char, 2);
+            assert_trim(Err("ałb"), "ałb", Unit::Char, 4);
+            assert_trim(Ok("ałb"),  "ałb", Unit::Byte, 0);
+            assert_trim(Err("ałb"), "ałb", Unit::Byte, 2);
+            assert_trim(Ok("b"),    "ałb", Unit::Byte, 3);
+            assert_trim(Ok(""),     "ałb", Unit::Byte, 4);
+            assert_trim(Err("ałb"), "ałb", Unit::Byte, 5);
+        }
+    }
+
+    mod trim_right_units_exactly {
+        use super::*;
+
+        fn assert_trim(expected: Result<&str, &str>, haystack: &str, unit: Unit, count: usize) {
+            let actual = haystack.trim_right_units_exactly(unit, count);
+
+            assert_eq!(expected, actual,
+                "For haystack '{}', unit '{:?}' and count '{}'", haystack, unit, count);
+        }
+
+        #[test]
+        fn returns_trimmed_or_original_str() {
+            assert_trim(Ok("bł"),   "bła", Unit::Char, 1);
+            assert_trim(Ok("b"),    "bła", Unit::Char, 2);
+            assert_trim(Err("bła"), "bła", Unit::Char, 4);
+            assert_trim(Ok("bła"),  "bła", Unit::Byte, 0);
+            assert_trim(Err("bła"), "bła", Unit::Byte, 2);
+            assert_trim(Ok("b"),    "bła", Unit::Byte, 3);
+            assert_trim(Ok(""),     "bła", Unit::Byte, 4);
+            assert_trim(Err("bła"), "bła", Unit::Byte, 5);
+        }
+    }
+}